@@ -100,57 +100,127 @@ where
             boundaries.push(acc);
         }
         boundaries.push(max);
+        Histogram::from_boundaries(elements, boundaries)
+    }
 
-        let mut num_larger_than_max = 0;
-        let mut num_less_than_min = 0;
-        let mut min_received = None;
-        let mut max_received = None;
-        let mut counters = vec![0usize; num_intervals];
+    /// Creates a Histogram whose intervals are delimited by the `boundaries`,
+    /// i.e. the i-th interval is `[boundaries[i], boundaries[i + 1])` except
+    /// for the last interval which also includes the last boundary. The
+    /// `boundaries` must contain at least two values and be strictly
+    /// increasing. The values from the vector of `elements` are then inserted
+    /// into the histogram.
+    ///
+    /// # Example
+    /// ```
+    /// use math::histogram::Histogram;
+    ///
+    /// let histogram =
+    ///     Histogram::from_boundaries(Some(&vec![1, 4, 5, 9, 12]), vec![
+    ///         0, 2, 5, 10,
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(histogram.get_counters(), &vec![1, 1, 2]);
+    /// assert_eq!(histogram.get_num_larger_than_max(), 1);
+    /// ```
+    pub fn from_boundaries<'a>(
+        elements: Option<&'a Vec<T>>,
+        boundaries: Vec<T>,
+    ) -> Result<Histogram<T>, String>
+    where
+        &'a T: Deref, {
+        if boundaries.len() < 2 {
+            return Err(format!(
+                "at least two boundaries are required, received {}",
+                boundaries.len()
+            ));
+        }
+        if let Some(i) = (1..boundaries.len()).find(|&i| {
+            boundaries[i - 1].partial_cmp(&boundaries[i])
+                != Some(cmp::Ordering::Less)
+        }) {
+            return Err(format!(
+                "boundaries have to be strictly increasing, received {} \
+                followed by {}",
+                boundaries[i - 1],
+                boundaries[i]
+            ));
+        }
+        let num_intervals = boundaries.len() - 1;
+        let mut histogram = Histogram {
+            boundaries,
+            counters: vec![0usize; num_intervals],
+            num_less_than_min: 0,
+            num_larger_than_max: 0,
+            min_received: None,
+            max_received: None,
+        };
         if let Some(elements) = elements {
-            for a in elements.iter() {
-                if *a < min {
-                    num_less_than_min += 1;
-                } else if *a > max {
-                    num_larger_than_max += 1;
-                } else {
-                    let i = match ((*a - min) / delta).to_usize() {
-                        Some(i) => i,
-                        None => {
-                            return Err(format!(
-                                "failed to convert {} to an usize index",
-                                (*a - min) / delta
-                            ))
-                        }
-                    };
-                    counters[cmp::min(i, num_intervals - 1)] += 1;
-                }
+            for &a in elements.iter() {
+                histogram.collect(a);
+            }
+        }
+        Ok(histogram)
+    }
 
-                match min_received {
-                    None => min_received = Some(*a),
-                    Some(m) => {
-                        if *a < m {
-                            min_received = Some(*a);
-                        }
-                    }
-                }
-                match max_received {
-                    None => max_received = Some(*a),
-                    Some(m) => {
-                        if *a > m {
-                            max_received = Some(*a);
-                        }
-                    }
+    /// Creates a Histogram consisting of `num_intervals` intervals between
+    /// `min` and `max` whose boundaries are equally spaced in log space, i.e.
+    /// the boundaries form a geometric progression. `min` has to be positive.
+    ///
+    /// # Example
+    /// ```
+    /// use math::histogram::Histogram;
+    ///
+    /// let histogram = Histogram::new_log_scale(
+    ///     Some(&vec![2., 50., 99., 100., 1000.]),
+    ///     3,
+    ///     1.,
+    ///     1000.,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(histogram.get_counters(), &vec![1, 2, 2]);
+    /// ```
+    pub fn new_log_scale<'a>(
+        elements: Option<&'a Vec<T>>,
+        num_intervals: usize,
+        min: T,
+        max: T,
+    ) -> Result<Histogram<T>, String>
+    where
+        &'a T: Deref, {
+        if num_intervals == 0 {
+            return Err(format!(
+                "num_intervals should be positive, received {}",
+                num_intervals
+            ));
+        }
+        if min <= T::zero() {
+            return Err(format!("min ({}) has to be positive", min));
+        }
+        if max <= min {
+            return Err(format!("max ({}) has to be > min ({})", max, min));
+        }
+        let (min_f64, max_f64) = match (min.to_f64(), max.to_f64()) {
+            (Some(min_f64), Some(max_f64)) => (min_f64, max_f64),
+            _ => {
+                return Err(format!(
+                    "failed to convert min ({}) and max ({}) to f64",
+                    min, max
+                ))
+            }
+        };
+        let log_delta = (max_f64.ln() - min_f64.ln()) / num_intervals as f64;
+        let mut boundaries = vec![min];
+        for i in 1..num_intervals {
+            let b = (min_f64.ln() + log_delta * i as f64).exp();
+            match T::from_f64(b) {
+                Some(b) => boundaries.push(b),
+                None => {
+                    return Err(format!("failed to convert {} to type T", b))
                 }
             }
         }
-        Ok(Histogram {
-            boundaries,
-            counters,
-            num_less_than_min,
-            num_larger_than_max,
-            min_received,
-            max_received,
-        })
+        boundaries.push(max);
+        Histogram::from_boundaries(elements, boundaries)
     }
 
     #[inline]
@@ -167,6 +237,43 @@ where
         ratio_distribution
     }
 
+    /// Returns the total number of observations, including those that fell
+    /// below the minimum or above the maximum boundary.
+    ///
+    /// # Example
+    /// ```
+    /// use math::histogram::Histogram;
+    ///
+    /// let histogram =
+    ///     Histogram::new(Some(&vec![2, -1, 3, 5, 12]), 5, 0, 10).unwrap();
+    /// assert_eq!(histogram.total_count(), 5);
+    /// ```
+    pub fn total_count(&self) -> usize {
+        self.counters.iter().sum::<usize>()
+            + self.num_less_than_min
+            + self.num_larger_than_max
+    }
+
+    /// Same as `get_ratios`, except that the counts are divided by
+    /// `total_count`, so the ratios will sum to less than 1 if there are
+    /// observations outside the range of the histogram.
+    ///
+    /// # Example
+    /// ```
+    /// use math::histogram::Histogram;
+    ///
+    /// let histogram =
+    ///     Histogram::new(Some(&vec![1, 6, -1, 12]), 2, 0, 10).unwrap();
+    /// assert_eq!(histogram.get_ratios_over_total(), vec![0.25, 0.25]);
+    /// ```
+    pub fn get_ratios_over_total(&self) -> Vec<f64> {
+        let total = self.total_count() as f64;
+        self.counters
+            .iter()
+            .map(|&count| count as f64 / total)
+            .collect()
+    }
+
     pub fn new_with_auto_range<'a>(
         elements: &'a Vec<T>,
         num_intervals: usize,
@@ -232,6 +339,64 @@ where
     pub fn max_boundary(&self) -> T {
         *self.boundaries.last().unwrap()
     }
+
+    /// Same as `==` except that the boundaries as well as the minimum and
+    /// maximum values received are only required to be within `tol` of each
+    /// other. All the counters still have to be exactly equal.
+    ///
+    /// # Example
+    /// ```
+    /// use math::histogram::Histogram;
+    ///
+    /// let h1 = Histogram::new(Some(&vec![0.5, 0.9]), 3, 0., 1.).unwrap();
+    /// let h2 = Histogram::new(Some(&vec![0.5, 0.9]), 3, 0., 1. + 1e-12).unwrap();
+    /// assert_ne!(h1, h2);
+    /// assert!(h1.approx_eq(&h2, 1e-9));
+    /// ```
+    pub fn approx_eq(&self, other: &Histogram<T>, tol: f64) -> bool {
+        let close = |a: T, b: T| match (a.to_f64(), b.to_f64()) {
+            (Some(a), Some(b)) => (a - b).abs() <= tol,
+            _ => false,
+        };
+        let close_option = |a: Option<T>, b: Option<T>| match (a, b) {
+            (None, None) => true,
+            (Some(a), Some(b)) => close(a, b),
+            _ => false,
+        };
+        self.counters == other.counters
+            && self.num_less_than_min == other.num_less_than_min
+            && self.num_larger_than_max == other.num_larger_than_max
+            && self.boundaries.len() == other.boundaries.len()
+            && self
+                .boundaries
+                .iter()
+                .zip(other.boundaries.iter())
+                .all(|(&a, &b)| close(a, b))
+            && close_option(self.min_received, other.min_received)
+            && close_option(self.max_received, other.max_received)
+    }
+
+    /// Returns an iterator over the `(center, count)` pairs of the intervals,
+    /// where the center of an interval `[lo, hi)` is `(lo + hi) / 2` computed
+    /// in the arithmetic of `T`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::histogram::Histogram;
+    ///
+    /// let histogram =
+    ///     Histogram::new(Some(&vec![4., 0., 3.5]), 2, 0., 7.).unwrap();
+    /// let mut iter = histogram.to_center_count_iter();
+    /// assert_eq!(Some((1.75, 1)), iter.next());
+    /// assert_eq!(Some((5.25, 2)), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    pub fn to_center_count_iter(&self) -> HistogramCenterCountIter<'_, T> {
+        HistogramCenterCountIter {
+            histogram: self,
+            cursor: 0,
+        }
+    }
 }
 
 impl<T> Collecting<T> for Histogram<T>
@@ -245,16 +410,16 @@ where
         + fmt::Display,
 {
     fn collect(&mut self, item: T) {
-        let delta = self.boundaries[1] - self.boundaries[0];
         let num_intervals = self.num_intervals();
-        let min_boundary = self.min_boundary();
-        if item < min_boundary {
+        if item < self.min_boundary() {
             self.num_less_than_min += 1;
         } else if item > self.max_boundary() {
             self.num_larger_than_max += 1;
         } else {
-            let i = ((item - min_boundary) / delta).to_usize().unwrap();
-            self.counters[cmp::min(i, num_intervals - 1)] += 1;
+            // the number of inner boundaries that are <= the item
+            let i = self.boundaries[1..num_intervals]
+                .partition_point(|&b| b <= item);
+            self.counters[i] += 1;
         }
 
         match self.min_received {
@@ -419,6 +584,49 @@ where
     }
 }
 
+/// An iterator that iterates through the `(center, count)` pairs of the
+/// histogram intervals. See `Histogram::to_center_count_iter`.
+pub struct HistogramCenterCountIter<'a, T>
+where
+    T: PartialOrd
+        + NumAssign
+        + NumOps
+        + FromPrimitive
+        + ToPrimitive
+        + Copy
+        + fmt::Display, {
+    histogram: &'a Histogram<T>,
+    cursor: usize,
+}
+
+impl<'a, T> Iterator for HistogramCenterCountIter<'a, T>
+where
+    T: PartialOrd
+        + NumAssign
+        + NumOps
+        + FromPrimitive
+        + ToPrimitive
+        + Copy
+        + fmt::Display,
+{
+    type Item = (T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.cursor;
+        if i >= self.histogram.num_intervals() {
+            None
+        } else {
+            self.cursor += 1;
+            let lo = self.histogram.boundaries[i];
+            let hi = self.histogram.boundaries[i + 1];
+            Some((
+                (lo + hi) / (T::one() + T::one()),
+                self.histogram.counters[i],
+            ))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::traits::{Collecting, ToIterator};
@@ -457,4 +665,118 @@ mod tests {
         assert_eq!(histogram.get_num_less_than_min(), 0);
         assert_eq!(histogram.get_num_larger_than_max(), 0);
     }
+
+    #[test]
+    fn test_approx_eq() {
+        let elements = vec![0.05, 0.25, 0.35, 0.95, 1.5, -0.1];
+        // 0.1 * 3 and 0.3 differ by floating rounding
+        let h1 = Histogram::new(Some(&elements), 3, 0., 0.1 * 3.).unwrap();
+        let h2 = Histogram::new(Some(&elements), 3, 0., 0.3).unwrap();
+        assert_ne!(h1.get_boundaries(), h2.get_boundaries());
+        assert_ne!(h1, h2);
+        assert!(h1.approx_eq(&h2, 1e-12));
+        assert!(h2.approx_eq(&h1, 1e-12));
+        assert!(h1.approx_eq(&h1, 0.));
+        assert!(!h1.approx_eq(&h2, 0.));
+
+        // the counters have to be exactly equal
+        let mut h3 = h2.clone();
+        h3.collect(0.15);
+        assert!(!h1.approx_eq(&h3, 1e-12));
+        let mut h4 = h2.clone();
+        h4.collect(2.);
+        assert!(!h1.approx_eq(&h4, 1e-12));
+
+        // different number of intervals
+        let h5 = Histogram::new(Some(&elements), 4, 0., 0.3).unwrap();
+        assert!(!h1.approx_eq(&h5, 1.));
+
+        // boundaries further apart than the tolerance
+        let h6 = Histogram::new(Some(&elements), 3, 0., 0.31).unwrap();
+        assert!(!h1.approx_eq(&h6, 1e-3));
+        assert!(h1.approx_eq(&h6, 0.1));
+    }
+
+    #[test]
+    fn test_from_boundaries() {
+        let elements = vec![-1, 0, 1, 2, 4, 5, 9, 10, 12];
+        let histogram =
+            Histogram::from_boundaries(Some(&elements), vec![0, 2, 5, 10])
+                .unwrap();
+        assert_eq!(histogram.get_counters(), &vec![2, 2, 3]);
+        assert_eq!(histogram.get_num_less_than_min(), 1);
+        assert_eq!(histogram.get_num_larger_than_max(), 1);
+        assert_eq!(histogram.get_min_received(), Some(-1));
+        assert_eq!(histogram.get_max_received(), Some(12));
+
+        assert!(Histogram::<i32>::from_boundaries(None, vec![]).is_err());
+        assert!(Histogram::from_boundaries(None, vec![1]).is_err());
+        assert!(Histogram::from_boundaries(None, vec![1, 3, 3]).is_err());
+        assert!(Histogram::from_boundaries(None, vec![1., 3., 2.]).is_err());
+        assert!(Histogram::from_boundaries(None, vec![1., f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn test_new_log_scale() {
+        let elements = vec![0.5, 1., 9.99, 10., 50., 100., 999., 1000., 1001.];
+        let histogram =
+            Histogram::new_log_scale(Some(&elements), 3, 1., 1000.).unwrap();
+        let expected = Histogram::from_boundaries(Some(&elements), vec![
+            1., 10., 100., 1000.,
+        ])
+        .unwrap();
+        assert!(histogram.approx_eq(&expected, 1e-9));
+        assert_eq!(histogram.get_counters(), &vec![2, 2, 3]);
+        assert_eq!(histogram.get_num_less_than_min(), 1);
+        assert_eq!(histogram.get_num_larger_than_max(), 1);
+
+        let mut histogram = Histogram::new_log_scale(None, 4, 2., 32.).unwrap();
+        assert!(histogram.approx_eq(
+            &Histogram::from_boundaries(None, vec![2., 4., 8., 16., 32.])
+                .unwrap(),
+            1e-9
+        ));
+        for &x in [2.5, 3., 5., 17., 20., 30.].iter() {
+            histogram.collect(x);
+        }
+        assert_eq!(histogram.get_counters(), &vec![2, 1, 0, 3]);
+
+        let histogram =
+            Histogram::new_log_scale(Some(&vec![1, 5, 10, 99, 100]), 2, 1, 100)
+                .unwrap();
+        assert_eq!(histogram.get_boundaries(), &vec![1, 10, 100]);
+        assert_eq!(histogram.get_counters(), &vec![2, 3]);
+
+        assert!(Histogram::new_log_scale(None, 0, 1., 10.).is_err());
+        assert!(Histogram::new_log_scale(None, 2, 0., 10.).is_err());
+        assert!(Histogram::new_log_scale(None, 2, -1., 10.).is_err());
+        assert!(Histogram::new_log_scale(None, 2, 10., 10.).is_err());
+        // integer boundaries that collapse are rejected
+        assert!(Histogram::new_log_scale(None, 10, 1, 4).is_err());
+    }
+
+    #[test]
+    fn test_collect_all() {
+        let mut histogram = Histogram::new(None, 2, 0., 7.).unwrap();
+        histogram.collect_all(vec![4., 0., 3.5, -1.]);
+        histogram.collect_all([6., 8.].iter().cloned());
+        assert_eq!(histogram.get_counters(), &vec![1, 3]);
+        assert_eq!(histogram.get_num_less_than_min(), 1);
+        assert_eq!(histogram.get_num_larger_than_max(), 1);
+        assert_eq!(histogram.get_min_received(), Some(-1.));
+        assert_eq!(histogram.get_max_received(), Some(8.));
+    }
+
+    #[test]
+    fn test_total_count_and_ratios_over_total() {
+        let mut histogram = Histogram::new(None, 2, 0., 8.).unwrap();
+        assert_eq!(histogram.total_count(), 0);
+        histogram.collect_all(vec![1., 2., 5., -3., 9., 10.]);
+        assert_eq!(histogram.total_count(), 6);
+        assert_eq!(histogram.get_ratios(), vec![2. / 3., 1. / 3.]);
+
+        let ratios = histogram.get_ratios_over_total();
+        assert_eq!(ratios, vec![2. / 6., 1. / 6.]);
+        assert!(ratios.iter().sum::<f64>() < 1.);
+    }
 }
@@ -1,10 +1,19 @@
 //! # Modeling mathematical intervals
 
 use crate::set::contiguous_integer_set::ContiguousIntegerSet;
+use float_interval::ContiguousFloatInterval;
 
+pub mod bounded_interval;
+pub mod float_interval;
 pub mod trait_impl;
 pub mod traits;
 
 pub type IntInterval<T> = ContiguousIntegerSet<T>;
 
+pub type I32Interval = ContiguousIntegerSet<i32>;
+
 pub type I64Interval = ContiguousIntegerSet<i64>;
+
+pub type U64Interval = ContiguousIntegerSet<u64>;
+
+pub type F64Interval = ContiguousFloatInterval<f64>;
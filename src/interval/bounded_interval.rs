@@ -0,0 +1,290 @@
+//! Integer intervals whose endpoints can each be either included or excluded
+
+use crate::{
+    interval::traits::{Interval, Topology},
+    set::{
+        contiguous_integer_set::ContiguousIntegerSet,
+        traits::{Finite, Set},
+    },
+};
+use num::{integer::Integer, traits::cast::ToPrimitive};
+
+/// Whether an endpoint of an interval is included in the interval
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Boundary {
+    Open,
+    Closed,
+}
+
+/// Represents the set of integers between `start` and `end`, where each of the
+/// two endpoints is included in the set if and only if its `Boundary` is
+/// `Closed`.
+///
+/// # Example
+/// ```
+/// use math::{
+///     interval::{bounded_interval::BoundedInterval, traits::Interval},
+///     set::traits::Set,
+/// };
+///
+/// let open = BoundedInterval::open(2, 5);
+/// assert!(open.contains(&3));
+/// assert!(!open.contains(&5));
+/// assert_eq!(open.length(), 2);
+///
+/// let left_open = BoundedInterval::left_open(2, 5);
+/// assert!(!left_open.contains(&2));
+/// assert!(left_open.contains(&5));
+/// assert_eq!(left_open.length(), 3);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BoundedInterval<E: Integer + Copy> {
+    start: E,
+    end: E,
+    start_boundary: Boundary,
+    end_boundary: Boundary,
+}
+
+impl<E: Integer + Copy> BoundedInterval<E> {
+    pub fn new(
+        start: E,
+        end: E,
+        start_boundary: Boundary,
+        end_boundary: Boundary,
+    ) -> Self {
+        BoundedInterval {
+            start,
+            end,
+            start_boundary,
+            end_boundary,
+        }
+    }
+
+    /// Creates the interval `[start, end]`
+    #[inline]
+    pub fn closed(start: E, end: E) -> Self {
+        BoundedInterval::new(start, end, Boundary::Closed, Boundary::Closed)
+    }
+
+    /// Creates the interval `(start, end)`
+    #[inline]
+    pub fn open(start: E, end: E) -> Self {
+        BoundedInterval::new(start, end, Boundary::Open, Boundary::Open)
+    }
+
+    /// Creates the interval `(start, end]`
+    #[inline]
+    pub fn left_open(start: E, end: E) -> Self {
+        BoundedInterval::new(start, end, Boundary::Open, Boundary::Closed)
+    }
+
+    /// Creates the interval `[start, end)`
+    #[inline]
+    pub fn right_open(start: E, end: E) -> Self {
+        BoundedInterval::new(start, end, Boundary::Closed, Boundary::Open)
+    }
+
+    #[inline]
+    pub fn get_start_boundary(&self) -> Boundary {
+        self.start_boundary
+    }
+
+    #[inline]
+    pub fn get_end_boundary(&self) -> Boundary {
+        self.end_boundary
+    }
+
+    fn num_open_boundaries(&self) -> usize {
+        [self.start_boundary, self.end_boundary]
+            .iter()
+            .filter(|&&b| b == Boundary::Open)
+            .count()
+    }
+
+    /// Returns the equivalent closed integer interval, or `None` if the
+    /// interval is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use math::interval::{bounded_interval::BoundedInterval, IntInterval};
+    ///
+    /// assert_eq!(
+    ///     BoundedInterval::right_open(2, 5).to_closed(),
+    ///     Some(IntInterval::new(2, 4))
+    /// );
+    /// assert_eq!(BoundedInterval::open(2, 3).to_closed(), None);
+    /// ```
+    pub fn to_closed(&self) -> Option<ContiguousIntegerSet<E>> {
+        if self.is_empty() {
+            return None;
+        }
+        let start = match self.start_boundary {
+            Boundary::Open => self.start + E::one(),
+            Boundary::Closed => self.start,
+        };
+        let end = match self.end_boundary {
+            Boundary::Open => self.end - E::one(),
+            Boundary::Closed => self.end,
+        };
+        Some(ContiguousIntegerSet::new(start, end))
+    }
+}
+
+impl<E: Integer + Copy> Set<E> for BoundedInterval<E> {
+    fn is_empty(&self) -> bool {
+        if self.start > self.end {
+            return true;
+        }
+        // avoids computing `end - 1` which can underflow unsigned types
+        let width = self.end - self.start;
+        match self.num_open_boundaries() {
+            0 => false,
+            1 => width.is_zero(),
+            _ => width <= E::one(),
+        }
+    }
+
+    fn contains(&self, item: &E) -> bool {
+        let item = *item;
+        let after_start = match self.start_boundary {
+            Boundary::Open => item > self.start,
+            Boundary::Closed => item >= self.start,
+        };
+        let before_end = match self.end_boundary {
+            Boundary::Open => item < self.end,
+            Boundary::Closed => item <= self.end,
+        };
+        after_start && before_end
+    }
+}
+
+/// `get_start` and `get_end` return the endpoints regardless of whether they
+/// are included in the interval, while `length` is the number of integers in
+/// the interval.
+impl<E: Integer + Copy> Interval<E> for BoundedInterval<E> {
+    /// Creates the closed interval `[start, end_inclusive]`
+    fn from_boundaries(start: E, end_inclusive: E) -> Self {
+        BoundedInterval::closed(start, end_inclusive)
+    }
+
+    #[inline]
+    fn get_start(&self) -> E {
+        self.start
+    }
+
+    #[inline]
+    fn get_end(&self) -> E {
+        self.end
+    }
+
+    fn length(&self) -> E {
+        if self.is_empty() {
+            E::zero()
+        } else {
+            let mut length = self.end - self.start + E::one();
+            for _ in 0..self.num_open_boundaries() {
+                length = length - E::one();
+            }
+            length
+        }
+    }
+}
+
+impl<E: Integer + Copy + ToPrimitive> Finite for BoundedInterval<E> {
+    fn size(&self) -> usize {
+        self.length().to_usize().unwrap()
+    }
+}
+
+impl<E: Integer + Copy> Topology for BoundedInterval<E> {
+    fn is_open(&self) -> bool {
+        self.start_boundary == Boundary::Open
+            && self.end_boundary == Boundary::Open
+    }
+
+    fn is_closed(&self) -> bool {
+        self.start_boundary == Boundary::Closed
+            && self.end_boundary == Boundary::Closed
+    }
+}
+
+impl<E: Integer + Copy> From<ContiguousIntegerSet<E>> for BoundedInterval<E> {
+    fn from(set: ContiguousIntegerSet<E>) -> Self {
+        let (start, end) = set.get_start_and_end();
+        BoundedInterval::closed(start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        interval::{
+            bounded_interval::{Boundary, BoundedInterval},
+            traits::{Interval, Topology},
+            IntInterval,
+        },
+        set::traits::{Finite, Set},
+    };
+
+    #[test]
+    fn test_open_interval() {
+        let interval = BoundedInterval::open(2, 5);
+        assert!(!interval.contains(&1));
+        assert!(!interval.contains(&2));
+        assert!(interval.contains(&3));
+        assert!(interval.contains(&4));
+        assert!(!interval.contains(&5));
+        assert!(!interval.contains(&6));
+        assert_eq!(interval.length(), 2);
+        assert_eq!(interval.size(), 2);
+        assert!(interval.is_open());
+        assert!(!interval.is_closed());
+        assert_eq!(interval.to_closed(), Some(IntInterval::new(3, 4)));
+    }
+
+    #[test]
+    fn test_half_open_and_closed_intervals() {
+        let left_open = BoundedInterval::left_open(2, 5);
+        assert!(!left_open.contains(&2));
+        assert!(left_open.contains(&5));
+        assert_eq!(left_open.length(), 3);
+        assert!(!left_open.is_open());
+        assert!(!left_open.is_closed());
+
+        let right_open = BoundedInterval::right_open(2, 5);
+        assert!(right_open.contains(&2));
+        assert!(!right_open.contains(&5));
+        assert_eq!(right_open.length(), 3);
+        assert_eq!(right_open.to_closed(), Some(IntInterval::new(2, 4)));
+
+        let closed: BoundedInterval<i32> = IntInterval::new(2, 5).into();
+        assert!(closed.contains(&2));
+        assert!(closed.contains(&5));
+        assert_eq!(closed.length(), 4);
+        assert!(closed.is_closed());
+        assert_eq!(closed, BoundedInterval::from_boundaries(2, 5));
+        assert_eq!(
+            closed,
+            BoundedInterval::new(2, 5, Boundary::Closed, Boundary::Closed)
+        );
+    }
+
+    #[test]
+    fn test_empty_bounded_interval() {
+        assert!(BoundedInterval::open(2, 3).is_empty());
+        assert!(!BoundedInterval::open(2, 4).is_empty());
+        assert!(BoundedInterval::left_open(2, 2).is_empty());
+        assert!(BoundedInterval::right_open(2, 2).is_empty());
+        assert!(!BoundedInterval::closed(2, 2).is_empty());
+        assert!(BoundedInterval::closed(3, 2).is_empty());
+        assert_eq!(BoundedInterval::open(2, 3).length(), 0);
+        assert_eq!(BoundedInterval::open(3, 2).length(), 0);
+        assert_eq!(BoundedInterval::open(2, 3).to_closed(), None);
+
+        // does not underflow for unsigned types
+        let interval: BoundedInterval<usize> = BoundedInterval::open(0, 0);
+        assert!(interval.is_empty());
+        assert_eq!(interval.size(), 0);
+        assert!(!interval.contains(&0));
+    }
+}
@@ -0,0 +1,215 @@
+//! Intervals over the real line represented by floating point endpoints
+
+use crate::{
+    interval::traits::{Coalesce, Interval},
+    set::traits::{Intersect, Set},
+};
+use num::Float;
+
+/// Represents the closed interval `[start, end]` of floating point numbers.
+/// The interval is empty if `start > end`.
+///
+/// # Example
+/// ```
+/// use math::{
+///     interval::{traits::Interval, F64Interval},
+///     set::traits::{Intersect, Set},
+/// };
+///
+/// let interval = F64Interval::new(0.5, 2.);
+/// assert_eq!(interval.length(), 1.5);
+/// assert!(interval.contains(&2.));
+/// assert!(!interval.contains(&2.1));
+/// assert_eq!(
+///     interval.intersect(&F64Interval::new(1., 3.)),
+///     Some(F64Interval::new(1., 2.))
+/// );
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug, PartialOrd)]
+pub struct ContiguousFloatInterval<F: Float> {
+    start: F,
+    end: F,
+}
+
+impl<F: Float> ContiguousFloatInterval<F> {
+    /// Creates the interval `[start, end]`, where both ends are inclusive.
+    pub fn new(start: F, end: F) -> Self {
+        ContiguousFloatInterval {
+            start,
+            end,
+        }
+    }
+
+    #[inline]
+    pub fn get_start_and_end(&self) -> (F, F) {
+        (self.start, self.end)
+    }
+}
+
+impl<F: Float> Set<F> for ContiguousFloatInterval<F> {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    #[inline]
+    fn contains(&self, item: &F) -> bool {
+        let item = *item;
+        item >= self.start && item <= self.end
+    }
+}
+
+impl<F: Float> Interval<F> for ContiguousFloatInterval<F> {
+    fn from_boundaries(start: F, end_inclusive: F) -> Self {
+        ContiguousFloatInterval::new(start, end_inclusive)
+    }
+
+    #[inline]
+    fn get_start(&self) -> F {
+        self.start
+    }
+
+    #[inline]
+    fn get_end(&self) -> F {
+        self.end
+    }
+
+    fn length(&self) -> F {
+        if self.start > self.end {
+            F::zero()
+        } else {
+            self.end - self.start
+        }
+    }
+}
+
+impl<F: Float>
+    Intersect<&ContiguousFloatInterval<F>, Option<ContiguousFloatInterval<F>>>
+    for ContiguousFloatInterval<F>
+{
+    fn intersect(
+        &self,
+        other: &ContiguousFloatInterval<F>,
+    ) -> Option<ContiguousFloatInterval<F>> {
+        if self.is_empty()
+            || other.is_empty()
+            || other.end < self.start
+            || other.start > self.end
+        {
+            None
+        } else {
+            Some(ContiguousFloatInterval::new(
+                self.start.max(other.start),
+                self.end.min(other.end),
+            ))
+        }
+    }
+
+    fn has_non_empty_intersection_with(
+        &self,
+        other: &ContiguousFloatInterval<F>,
+    ) -> bool {
+        self.intersect(other).is_some()
+    }
+}
+
+/// returns an interval if only if the two intervals overlap or touch, i.e.
+/// the end of one interval is equal to the start of the other.
+/// An empty interval can be merged with any other non-empty interval
+impl<F: Float> Coalesce<Self> for ContiguousFloatInterval<F> {
+    fn coalesce_with(&self, other: &Self) -> Option<Self> {
+        if self.is_empty() && other.is_empty() {
+            None
+        } else if self.is_empty() {
+            Some(*other)
+        } else if other.is_empty() {
+            Some(*self)
+        } else if self.start > other.end || self.end < other.start {
+            None
+        } else {
+            Some(ContiguousFloatInterval::new(
+                self.start.min(other.start),
+                self.end.max(other.end),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        interval::{
+            traits::{Coalesce, Interval},
+            F64Interval,
+        },
+        set::traits::{Intersect, Set},
+    };
+
+    #[test]
+    fn test_f64_interval_intersect() {
+        let interval = F64Interval::new(-1.5, 2.5);
+        assert_eq!(
+            interval.intersect(&F64Interval::new(0., 4.)),
+            Some(F64Interval::new(0., 2.5))
+        );
+        assert_eq!(
+            interval.intersect(&F64Interval::new(-0.5, 0.5)),
+            Some(F64Interval::new(-0.5, 0.5))
+        );
+        // intervals sharing a single point intersect at that point
+        assert_eq!(
+            interval.intersect(&F64Interval::new(2.5, 3.)),
+            Some(F64Interval::new(2.5, 2.5))
+        );
+        assert_eq!(interval.intersect(&F64Interval::new(2.6, 3.)), None);
+        assert_eq!(interval.intersect(&F64Interval::new(1., 0.)), None);
+        assert!(interval
+            .has_non_empty_intersection_with(&F64Interval::new(-3., -1.5)));
+        assert!(!interval
+            .has_non_empty_intersection_with(&F64Interval::new(-3., -1.6)));
+    }
+
+    #[test]
+    fn test_f64_interval_coalesce() {
+        let interval = F64Interval::new(0., 1.);
+        assert_eq!(
+            interval.coalesce_with(&F64Interval::new(0.5, 2.)),
+            Some(F64Interval::new(0., 2.))
+        );
+        assert_eq!(
+            interval.coalesce_with(&F64Interval::new(-1., 0.)),
+            Some(F64Interval::new(-1., 1.))
+        );
+        assert_eq!(
+            interval.coalesce_with(&F64Interval::new(1., 3.)),
+            Some(F64Interval::new(0., 3.))
+        );
+        assert_eq!(interval.coalesce_with(&F64Interval::new(1.01, 3.)), None);
+        assert_eq!(
+            interval.coalesce_with(&F64Interval::new(0.2, 0.3)),
+            Some(interval)
+        );
+        assert_eq!(
+            interval.coalesce_with(&F64Interval::new(3., 2.)),
+            Some(interval)
+        );
+        assert_eq!(
+            F64Interval::new(3., 2.).coalesce_with(&F64Interval::new(1., 0.)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_f64_interval_length_and_contains() {
+        let interval = F64Interval::new(1., 3.5);
+        assert_eq!(interval.length(), 2.5);
+        assert_eq!(F64Interval::new(1., 1.).length(), 0.);
+        assert_eq!(F64Interval::new(2., 1.).length(), 0.);
+        assert!(interval.contains(&1.));
+        assert!(interval.contains(&3.5));
+        assert!(!interval.contains(&0.99));
+        assert!(!F64Interval::new(2., 1.).contains(&1.5));
+        assert!(F64Interval::new(2., 1.).is_empty());
+        assert_eq!(F64Interval::from_boundaries(1., 3.5), interval);
+    }
+}
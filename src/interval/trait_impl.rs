@@ -11,7 +11,13 @@ impl<I: Coalesce<I> + Interval<E> + Clone, E: Integer + Copy>
         intervals
     }
 
+    /// Empty intervals are removed. The remaining intervals are sorted by
+    /// their starts, so the last coalesced interval always has the largest
+    /// end seen so far. Any interval nested in one of the earlier intervals is
+    /// therefore absorbed into the last coalesced interval, no matter how many
+    /// intervals it is nested in.
     fn coalesce_intervals_inplace(&mut self) {
+        self.retain(|i| !i.is_empty());
         self.sort_by_key(|i| i.get_start());
         let mut coalesced_intervals = Vec::new();
         for interval in self.drain(..) {
@@ -52,4 +58,36 @@ mod tests {
             ContiguousIntegerSet::new(9, 10)
         ])
     }
+
+    #[test]
+    fn test_coalesce_nested_intervals() {
+        fn test(intervals: &[[i32; 2]], expected: &[[i32; 2]]) {
+            let intervals: Vec<ContiguousIntegerSet<i32>> = intervals
+                .iter()
+                .map(|[start, end]| ContiguousIntegerSet::new(*start, *end))
+                .collect();
+            let expected: Vec<ContiguousIntegerSet<i32>> = expected
+                .iter()
+                .map(|[start, end]| ContiguousIntegerSet::new(*start, *end))
+                .collect();
+            assert_eq!(intervals.to_coalesced_intervals(), expected);
+            assert_eq!(intervals.into_coalesced(), expected);
+        }
+        test(&[[0, 10], [2, 3], [5, 6]], &[[0, 10]]);
+        test(&[[5, 6], [2, 3], [0, 10]], &[[0, 10]]);
+        test(&[[0, 10], [1, 9], [2, 8], [3, 7]], &[[0, 10]]);
+        test(&[[2, 8], [0, 10], [0, 4], [10, 10]], &[[0, 10]]);
+        test(&[[0, 10], [2, 3], [12, 15], [13, 14], [11, 11]], &[[0, 15]]);
+        test(&[[0, 10], [2, 3], [13, 15], [14, 14]], &[[0, 10], [13, 15]]);
+
+        // identical intervals
+        test(&[[1, 3], [1, 3]], &[[1, 3]]);
+        test(&[[1, 3], [1, 3], [1, 3], [6, 6], [6, 6]], &[[1, 3], [6, 6]]);
+
+        // empty intervals are removed
+        test(&[[3, 2], [4, 1], [5, 6]], &[[5, 6]]);
+        test(&[[0, 10], [7, 3], [20, 12]], &[[0, 10]]);
+        test(&[[3, 2], [4, 1]], &[]);
+        test(&[], &[]);
+    }
 }
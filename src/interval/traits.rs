@@ -1,4 +1,4 @@
-use crate::set::traits::Set;
+use crate::set::traits::{Intersect, Set};
 use num::Num;
 
 /// A one-dimensional interval with a start and an end.
@@ -30,6 +30,52 @@ where
             Some(self.get_end())
         }
     }
+
+    /// The point halfway between the start and the end, rounded towards the
+    /// start for integer types. The result is unspecified for empty intervals.
+    ///
+    /// # Example
+    /// ```
+    /// use math::interval::{traits::Interval, F64Interval, IntInterval};
+    ///
+    /// assert_eq!(IntInterval::new(2, 6).midpoint(), 4);
+    /// assert_eq!(IntInterval::new(2, 5).midpoint(), 3);
+    /// assert_eq!(F64Interval::new(2., 5.).midpoint(), 3.5);
+    /// ```
+    fn midpoint(&self) -> T {
+        let start = self.get_start();
+        start + (self.get_end() - start) / (T::one() + T::one())
+    }
+
+    /// Whether the two intervals have a non-empty intersection
+    fn overlaps(&self, other: &Self) -> bool
+    where
+        Self: for<'a> Intersect<&'a Self, Option<Self>> + Sized, {
+        self.has_non_empty_intersection_with(other)
+    }
+
+    /// Returns the portion of the interval lying within the `bound`, or
+    /// `None` if the two are disjoint.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     interval::traits::Interval,
+    ///     set::contiguous_integer_set::ContiguousIntegerSet,
+    /// };
+    ///
+    /// let bound = ContiguousIntegerSet::new(0, 10);
+    /// assert_eq!(
+    ///     ContiguousIntegerSet::new(-5, 3).clamp_to(&bound),
+    ///     Some(ContiguousIntegerSet::new(0, 3))
+    /// );
+    /// assert_eq!(ContiguousIntegerSet::new(12, 15).clamp_to(&bound), None);
+    /// ```
+    fn clamp_to(&self, bound: &Self) -> Option<Self>
+    where
+        Self: for<'a> Intersect<&'a Self, Option<Self>> + Sized, {
+        self.intersect(bound)
+    }
 }
 
 pub trait Coalesce<T>: Sized {
@@ -10,10 +10,16 @@ pub mod weighted_sum;
 pub use binned_interval_iter::{
     AggregateOp, BinnedIntervalIter, IntoBinnedIntervalIter,
 };
-pub use common_refinement_zip::{CommonRefinementZip, CommonRefinementZipped};
+pub use common_refinement_zip::{
+    CommonRefinementZip, CommonRefinementZipped, RefinementError,
+    TryCommonRefinementZipped,
+};
 pub use concatenated_iter::{ConcatenatedIter, IntoConcatIter};
-pub use flat_zip::{FlatZipIter, IntoFlatZipIter};
+pub use flat_zip::{
+    FlatZipIter, FlatZipLongestIter, IntoFlatZipIter, IntoFlatZipLongestIter,
+};
 pub use union_zip::{
-    AsUnionZipped, IntoUnionZip, UnionZip, UnionZipped, UnionZippedIter,
+    AsUnionZipped, IntersectionZip, IntersectionZipped, IntersectionZippedIter,
+    IntoIntersectionZip, IntoUnionZip, UnionZip, UnionZipped, UnionZippedIter,
 };
-pub use weighted_sum::WeightedSum;
+pub use weighted_sum::{WeightedSum, WeightedSumInt};
@@ -1,52 +1,113 @@
 //! Binning the iterator output into fixed size intervals if the output
-//! is of the form `(I64Interval, T)`. Only bins with non-empty intersections
-//! with those intervals will be returned.
+//! is of the form `(IntInterval<B>, T)`, where the integer type `B` is `i64` by
+//! default. Only bins with non-empty intersections with those intervals will be
+//! returned.
 
 use crate::{
-    interval::{traits::Interval, I64Interval},
+    interval::{traits::Interval, IntInterval},
     iter::CommonRefinementZip,
     set::traits::{Finite, Intersect},
 };
-use num::{FromPrimitive, Num};
-use std::cmp::Ordering;
+use num::{FromPrimitive, Integer, Num, ToPrimitive};
+use std::{cmp::Ordering, fmt};
 
 /// The value of the associated with `Min` and `Max` are the initial min and max
 /// values.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum AggregateOp {
     Average,
+    /// The value of the first interval overlapping the bin in iteration order
+    First,
+    /// The value of the last interval overlapping the bin in iteration order
+    Last,
     Max,
     Min,
     Sum,
 }
 
-pub trait IntoBinnedIntervalIter<V>
+pub trait IntoBinnedIntervalIter<V, B = i64>
 where
     Self: Iterator + Sized,
-    V: Copy + Num + FromPrimitive + PartialOrd, {
+    V: Copy + Num + FromPrimitive + PartialOrd,
+    B: Integer + Copy + ToPrimitive + fmt::Debug, {
     fn into_binned_interval_iter(
         self,
-        bin_size: i64,
+        bin_size: B,
         aggregate_op: AggregateOp,
-        interval_value_extractor: Box<
-            dyn Fn(<Self as Iterator>::Item) -> (I64Interval, V),
+        interval_value_extractor: IntervalValueExtractor<
+            <Self as Iterator>::Item,
+            B,
+            V,
         >,
-    ) -> BinnedIntervalIter<Self, V>;
+    ) -> BinnedIntervalIter<Self, V, B>;
+
+    fn into_binned_interval_iter_with_offset(
+        self,
+        bin_size: B,
+        bin_offset: B,
+        aggregate_op: AggregateOp,
+        interval_value_extractor: IntervalValueExtractor<
+            <Self as Iterator>::Item,
+            B,
+            V,
+        >,
+    ) -> BinnedIntervalIter<Self, V, B>;
+
+    /// Same as `into_binned_interval_iter` except that the extractor is stored
+    /// as is instead of being boxed, avoiding the heap allocation and the
+    /// dynamic dispatch for every item.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     interval::I64Interval,
+    ///     iter::binned_interval_iter::{AggregateOp, IntoBinnedIntervalIter},
+    /// };
+    ///
+    /// let intervals = vec![(I64Interval::new(0, 6), 1)];
+    /// let binned: Vec<(I64Interval, i32)> = intervals
+    ///     .into_iter()
+    ///     .into_binned_interval_iter_by(5, AggregateOp::Sum, |(i, v)| (i, v * 2))
+    ///     .collect();
+    /// assert_eq!(binned, vec![
+    ///     (I64Interval::new(0, 4), 10),
+    ///     (I64Interval::new(5, 9), 4)
+    /// ]);
+    /// ```
+    fn into_binned_interval_iter_by<F>(
+        self,
+        bin_size: B,
+        aggregate_op: AggregateOp,
+        interval_value_extractor: F,
+    ) -> BinnedIntervalIter<Self, V, B, F>
+    where
+        F: Fn(<Self as Iterator>::Item) -> (IntInterval<B>, V), {
+        BinnedIntervalIter::with_offset_by(
+            self,
+            bin_size,
+            B::zero(),
+            aggregate_op,
+            interval_value_extractor,
+        )
+    }
 }
 
-impl<I, V> IntoBinnedIntervalIter<V> for I
+impl<I, V, B> IntoBinnedIntervalIter<V, B> for I
 where
     I: Iterator,
     V: Copy + Num + FromPrimitive + PartialOrd,
+    B: Integer + Copy + ToPrimitive + fmt::Debug,
 {
     fn into_binned_interval_iter(
         self,
-        bin_size: i64,
+        bin_size: B,
         aggregate_op: AggregateOp,
-        interval_value_extractor: Box<
-            dyn Fn(<I as Iterator>::Item) -> (I64Interval, V),
+        interval_value_extractor: IntervalValueExtractor<
+            <I as Iterator>::Item,
+            B,
+            V,
         >,
-    ) -> BinnedIntervalIter<Self, V> {
+    ) -> BinnedIntervalIter<Self, V, B> {
         BinnedIntervalIter::new(
             self,
             bin_size,
@@ -54,11 +115,33 @@ where
             interval_value_extractor,
         )
     }
+
+    fn into_binned_interval_iter_with_offset(
+        self,
+        bin_size: B,
+        bin_offset: B,
+        aggregate_op: AggregateOp,
+        interval_value_extractor: IntervalValueExtractor<
+            <I as Iterator>::Item,
+            B,
+            V,
+        >,
+    ) -> BinnedIntervalIter<Self, V, B> {
+        BinnedIntervalIter::with_offset(
+            self,
+            bin_size,
+            bin_offset,
+            aggregate_op,
+            interval_value_extractor,
+        )
+    }
 }
 
-/// With imaginary bins of size `bin_size` and aligned at `0`,
-/// returns a value for each bin that intersects one or more intervals from
-/// the original iterator `iter`, where the value at each intersection is
+type IntervalValueExtractor<X, B, V> = Box<dyn Fn(X) -> (IntInterval<B>, V)>;
+
+/// With imaginary bins of size `bin_size` and aligned at `bin_offset` (`0` by
+/// default), returns a value for each bin that intersects one or more intervals
+/// from the original iterator `iter`, where the value at each intersection is
 /// obtained by applying the operation specified by the `aggregate_op` for
 /// all the overlapping intervals and their associated values, where the value
 /// of each overlapping interval is multiplied by the length of the interval if
@@ -154,39 +237,127 @@ where
 ///     ]
 /// );
 /// ```
-pub struct BinnedIntervalIter<I, V>
-where
+///
+/// The extractor is boxed by default, but can be any `F` implementing
+/// `Fn(I::Item) -> (IntInterval<B>, V)`, see `with_offset_by`.
+pub struct BinnedIntervalIter<
+    I,
+    V,
+    B = i64,
+    F = IntervalValueExtractor<<I as Iterator>::Item, B, V>,
+> where
     I: Iterator,
-    V: Copy + Num + FromPrimitive + PartialOrd, {
+    V: Copy + Num + FromPrimitive + PartialOrd,
+    B: Integer + Copy + ToPrimitive + fmt::Debug,
+    F: Fn(I::Item) -> (IntInterval<B>, V), {
     iter: I,
-    bin_size: i64,
+    bin_size: B,
+    bin_offset: B,
     aggregate_op: AggregateOp,
-    iter_item_interval_value_extractor:
-        Box<dyn Fn(<I as Iterator>::Item) -> (I64Interval, V)>,
-    current_interval_val: Option<(I64Interval, V)>,
-    current_bin: Option<I64Interval>,
+    iter_item_interval_value_extractor: F,
+    current_interval_val: Option<(IntInterval<B>, V)>,
+    current_bin: Option<IntInterval<B>>,
 }
 
-impl<I, V> BinnedIntervalIter<I, V>
+impl<I, V, B> BinnedIntervalIter<I, V, B>
 where
     I: Iterator,
     V: Copy + Num + FromPrimitive + PartialOrd,
+    B: Integer + Copy + ToPrimitive + fmt::Debug,
 {
     pub fn new(
-        mut iter: I,
-        bin_size: i64,
+        iter: I,
+        bin_size: B,
         aggregate_op: AggregateOp,
-        iter_item_interval_value_extractor: Box<
-            dyn Fn(<I as Iterator>::Item) -> (I64Interval, V),
+        iter_item_interval_value_extractor: IntervalValueExtractor<
+            <I as Iterator>::Item,
+            B,
+            V,
         >,
     ) -> Self {
-        assert!(bin_size >= 1, "bin_size must be at least 1");
+        BinnedIntervalIter::with_offset(
+            iter,
+            bin_size,
+            B::zero(),
+            aggregate_op,
+            iter_item_interval_value_extractor,
+        )
+    }
+
+    /// Creates a `BinnedIntervalIter` whose bins are aligned at
+    /// `bin_offset + k * bin_size` for integers `k`.
+    ///
+    /// # Panics
+    /// The iterator will panic in debug builds if the start of a bin cannot be
+    /// represented by `B`, e.g. when an unsigned `B` would require a negative
+    /// bin start.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     interval::I64Interval,
+    ///     iter::binned_interval_iter::{AggregateOp, BinnedIntervalIter},
+    /// };
+    ///
+    /// let intervals = vec![(I64Interval::new(0, 4), 1)];
+    /// let binned: Vec<(I64Interval, i32)> = BinnedIntervalIter::with_offset(
+    ///     intervals.into_iter(),
+    ///     5,
+    ///     2,
+    ///     AggregateOp::Sum,
+    ///     Box::new(|(interval, val)| (interval, val)),
+    /// )
+    /// .collect();
+    /// assert_eq!(binned, vec![
+    ///     (I64Interval::new(-3, 1), 2),
+    ///     (I64Interval::new(2, 6), 3)
+    /// ]);
+    /// ```
+    pub fn with_offset(
+        iter: I,
+        bin_size: B,
+        bin_offset: B,
+        aggregate_op: AggregateOp,
+        iter_item_interval_value_extractor: IntervalValueExtractor<
+            <I as Iterator>::Item,
+            B,
+            V,
+        >,
+    ) -> Self {
+        BinnedIntervalIter::with_offset_by(
+            iter,
+            bin_size,
+            bin_offset,
+            aggregate_op,
+            iter_item_interval_value_extractor,
+        )
+    }
+}
+
+impl<I, V, B, F> BinnedIntervalIter<I, V, B, F>
+where
+    I: Iterator,
+    V: Copy + Num + FromPrimitive + PartialOrd,
+    B: Integer + Copy + ToPrimitive + fmt::Debug,
+    F: Fn(I::Item) -> (IntInterval<B>, V),
+{
+    /// Same as `with_offset` except that the extractor can be of any type
+    /// implementing `Fn` instead of a boxed `dyn Fn`.
+    pub fn with_offset_by(
+        mut iter: I,
+        bin_size: B,
+        bin_offset: B,
+        aggregate_op: AggregateOp,
+        iter_item_interval_value_extractor: F,
+    ) -> Self {
+        assert!(bin_size >= B::one(), "bin_size must be at least 1");
         let current_interval_val = iter
             .next()
             .map(|item| iter_item_interval_value_extractor(item));
         BinnedIntervalIter {
             iter,
             bin_size,
+            bin_offset,
             aggregate_op,
             iter_item_interval_value_extractor,
             current_interval_val,
@@ -195,12 +366,14 @@ where
     }
 }
 
-impl<I, V> Iterator for BinnedIntervalIter<I, V>
+impl<I, V, B, F> Iterator for BinnedIntervalIter<I, V, B, F>
 where
     I: Iterator,
     V: Copy + Num + FromPrimitive + PartialOrd,
+    B: Integer + Copy + ToPrimitive + fmt::Debug,
+    F: Fn(I::Item) -> (IntInterval<B>, V),
 {
-    type Item = (I64Interval, V);
+    type Item = (IntInterval<B>, V);
 
     /// After every iteration, `self.current_bin` can be
     /// * `None`: indicating that the current interval has not been processed at
@@ -223,14 +396,22 @@ where
 
                 let interval_start = interval.get_start();
 
-                // the start of the first bin that overlaps the interval
-                let first_overlap_bin_start = if interval_start >= 0 {
-                    (interval_start / self.bin_size) * self.bin_size
-                } else {
-                    // take the ceiling towards the negative direction
-                    ((interval_start - (self.bin_size - 1)) / self.bin_size)
-                        * self.bin_size
-                };
+                // the start of the first bin that overlaps the interval, where
+                // the bins are aligned at `self.bin_offset`. The subtractions
+                // are ordered to avoid underflowing unsigned types.
+                let first_overlap_bin_start =
+                    if interval_start >= self.bin_offset {
+                        interval_start
+                            - (interval_start - self.bin_offset) % self.bin_size
+                    } else {
+                        let gap =
+                            (self.bin_offset - interval_start) % self.bin_size;
+                        if gap.is_zero() {
+                            interval_start
+                        } else {
+                            interval_start - (self.bin_size - gap)
+                        }
+                    };
 
                 let bin_start = match self.current_bin {
                     None => {
@@ -241,17 +422,20 @@ where
                         if old_bin.get_end() < interval_start {
                             first_overlap_bin_start
                         } else {
-                            old_bin.get_end() + 1
+                            old_bin.get_end() + B::one()
                         }
                     }
                 };
-                let bin_end_inclusive = bin_start + self.bin_size - 1;
+                let bin_end_inclusive = bin_start + self.bin_size - B::one();
                 self.current_bin =
-                    Some(I64Interval::new(bin_start, bin_end_inclusive));
-                let bin_size_denominator = V::from_i64(self.bin_size).unwrap();
+                    Some(IntInterval::new(bin_start, bin_end_inclusive));
+                let bin_size_denominator =
+                    self.bin_size.to_i64().and_then(V::from_i64).unwrap();
 
                 loop {
                     aggregate = match self.aggregate_op {
+                        AggregateOp::First => Some(aggregate.unwrap_or(val)),
+                        AggregateOp::Last => Some(val),
                         AggregateOp::Max => Some(aggregate.map_or_else(
                             || val,
                             |agg| match agg.partial_cmp(&val).unwrap() {
@@ -309,7 +493,7 @@ where
                             Some((i, v)) => {
                                 assert!(
                                     interval_end_inclusive < i.get_start(),
-                                    "previous interval end ({}) >= next interval start ({})",
+                                    "previous interval end ({:?}) >= next interval start ({:?})",
                                     interval_end_inclusive,
                                     i.get_start()
                                 );
@@ -334,17 +518,17 @@ where
     }
 }
 
-type IntType = i64;
-
-impl<I, V> CommonRefinementZip<IntType, (I64Interval, V), I64Interval, V>
-    for BinnedIntervalIter<I, V>
+impl<I, V, B, F> CommonRefinementZip<B, (IntInterval<B>, V), IntInterval<B>, V>
+    for BinnedIntervalIter<I, V, B, F>
 where
     I: Iterator,
     V: Copy + Num + FromPrimitive + PartialOrd,
+    B: Integer + Copy + ToPrimitive + fmt::Debug,
+    F: Fn(I::Item) -> (IntInterval<B>, V),
 {
     fn get_interval_value_extractor(
         &self,
-    ) -> Box<dyn Fn(<Self as Iterator>::Item) -> (I64Interval, V)> {
+    ) -> IntervalValueExtractor<<Self as Iterator>::Item, B, V> {
         Box::new(|item| (item.0, item.1))
     }
 }
@@ -352,13 +536,16 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        interval::I64Interval,
+        interval::{I32Interval, I64Interval, IntInterval, U64Interval},
         iter::{
-            binned_interval_iter::{AggregateOp, IntoBinnedIntervalIter},
+            binned_interval_iter::{
+                AggregateOp, BinnedIntervalIter, IntoBinnedIntervalIter,
+            },
             CommonRefinementZip,
         },
         partition::integer_interval_map::IntegerIntervalMap,
     };
+    use std::collections::BTreeMap;
 
     #[test]
     fn test_binned_interval_iter() {
@@ -449,6 +636,319 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_binned_interval_iter_with_offset() {
+        let bin_size = 3;
+        let mut interval_map = IntegerIntervalMap::new();
+        interval_map.aggregate(I64Interval::new(-1, 4), 2);
+        interval_map.aggregate(I64Interval::new(6, 8), 4);
+        interval_map.aggregate(I64Interval::new(4, 7), 1);
+
+        // interval coordinates              | value
+        // -1 0 | 1 2 3 | 4     |            | +2
+        //      |       |     6 | 7 8        | +4
+        //      |       | 4 5 6 | 7          | +1
+        //------------------------------------
+        //  2 2 | 2 2 2 | 3 1 5 | 5 4 |      | superposed values
+        //  4   || 6    || 9    || 9  ||     | bin sum
+        //  2   || 2    || 5    || 5  ||     | bin max
+        //  2   || 2    || 1    || 4  ||     | bin min
+
+        macro_rules! get_actual {
+            ($offset:expr, $op:expr) => {
+                interval_map
+                    .iter()
+                    .into_binned_interval_iter_with_offset(
+                        bin_size,
+                        $offset,
+                        $op,
+                        Box::new(|(&interval, &val)| (interval, val)),
+                    )
+                    .collect::<Vec<(I64Interval, i32)>>()
+            };
+        }
+
+        for &offset in [1, -2, 7].iter() {
+            assert_eq!(get_actual!(offset, AggregateOp::Sum), vec![
+                (I64Interval::new(-2, 0), 4),
+                (I64Interval::new(1, 3), 6),
+                (I64Interval::new(4, 6), 9),
+                (I64Interval::new(7, 9), 9),
+            ]);
+            assert_eq!(get_actual!(offset, AggregateOp::Max), vec![
+                (I64Interval::new(-2, 0), 2),
+                (I64Interval::new(1, 3), 2),
+                (I64Interval::new(4, 6), 5),
+                (I64Interval::new(7, 9), 5),
+            ]);
+            assert_eq!(get_actual!(offset, AggregateOp::Min), vec![
+                (I64Interval::new(-2, 0), 2),
+                (I64Interval::new(1, 3), 2),
+                (I64Interval::new(4, 6), 1),
+                (I64Interval::new(7, 9), 4),
+            ]);
+        }
+
+        // an offset that is a multiple of the bin size is the same as no
+        // offset
+        for &op in [AggregateOp::Sum, AggregateOp::Max, AggregateOp::Min].iter()
+        {
+            assert_eq!(
+                get_actual!(-6, op),
+                interval_map
+                    .iter()
+                    .into_binned_interval_iter(
+                        bin_size,
+                        op,
+                        Box::new(|(&interval, &val)| (interval, val)),
+                    )
+                    .collect::<Vec<(I64Interval, i32)>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_binned_interval_iter_first_last() {
+        let bin_size = 4;
+        let mut interval_map = IntegerIntervalMap::new();
+        interval_map.aggregate(I64Interval::new(0, 1), 3);
+        interval_map.aggregate(I64Interval::new(2, 5), -1);
+        interval_map.aggregate(I64Interval::new(9, 9), 7);
+
+        // interval coordinates           | value
+        // 0 1     |     |                | +3
+        //     2 3 | 4 5 |                | -1
+        //         |     |   9            | +7
+        //---------------------------------
+        //  3   || -1    ||  7            | bin first
+        //  -1  || -1    ||  7            | bin last
+
+        macro_rules! get_actual {
+            ($op:expr) => {
+                interval_map
+                    .iter()
+                    .into_binned_interval_iter(
+                        bin_size,
+                        $op,
+                        Box::new(|(&interval, &val)| (interval, val)),
+                    )
+                    .collect::<Vec<(I64Interval, i32)>>()
+            };
+        }
+
+        assert_eq!(get_actual!(AggregateOp::First), vec![
+            (I64Interval::new(0, 3), 3),
+            (I64Interval::new(4, 7), -1),
+            (I64Interval::new(8, 11), 7),
+        ]);
+        assert_eq!(get_actual!(AggregateOp::Last), vec![
+            (I64Interval::new(0, 3), -1),
+            (I64Interval::new(4, 7), -1),
+            (I64Interval::new(8, 11), 7),
+        ]);
+    }
+
+    #[test]
+    fn test_binned_interval_iter_generic_bin_type() {
+        let map: BTreeMap<IntInterval<usize>, i32> =
+            vec![(IntInterval::new(1, 4), 2), (IntInterval::new(6, 8), 4)]
+                .into_iter()
+                .collect();
+
+        // interval coordinates   | value
+        // 1 2 | 3 4   |          | +2
+        //     |       | 6 7 8    | +4
+        //---------------------------
+        //  4  || 4    || 12      | bin sum
+        let actual: Vec<(IntInterval<usize>, i32)> = map
+            .iter()
+            .into_binned_interval_iter(
+                3,
+                AggregateOp::Sum,
+                Box::new(|(&interval, &val)| (interval, val)),
+            )
+            .collect();
+        assert_eq!(actual, vec![
+            (IntInterval::new(0, 2), 4),
+            (IntInterval::new(3, 5), 4),
+            (IntInterval::new(6, 8), 12),
+        ]);
+
+        // interval coordinates   | value
+        // 1 2 3 | 4     |        | +2
+        //       |     6 | 7 8    | +4
+        //---------------------------
+        //  6    || 6    || 8     | bin sum
+        let actual: Vec<(IntInterval<usize>, i32)> = map
+            .iter()
+            .into_binned_interval_iter_with_offset(
+                3,
+                4,
+                AggregateOp::Sum,
+                Box::new(|(&interval, &val)| (interval, val)),
+            )
+            .collect();
+        assert_eq!(actual, vec![
+            (IntInterval::new(1, 3), 6),
+            (IntInterval::new(4, 6), 6),
+            (IntInterval::new(7, 9), 8),
+        ]);
+
+        // the same binning with i32 coordinates
+        let actual: Vec<(IntInterval<i32>, i32)> = map
+            .iter()
+            .into_binned_interval_iter_with_offset(
+                3,
+                4,
+                AggregateOp::Sum,
+                Box::new(|(&interval, &val)| {
+                    let (start, end) = interval.get_start_and_end();
+                    (IntInterval::new(start as i32, end as i32), val)
+                }),
+            )
+            .collect();
+        assert_eq!(actual, vec![
+            (IntInterval::new(1, 3), 6),
+            (IntInterval::new(4, 6), 6),
+            (IntInterval::new(7, 9), 8),
+        ]);
+
+        let refined: Vec<(IntInterval<usize>, Vec<Option<i32>>)> = map
+            .iter()
+            .into_binned_interval_iter(
+                3,
+                AggregateOp::Max,
+                Box::new(|(&interval, &val)| (interval, val)),
+            )
+            .common_refinement_zip(map.iter())
+            .collect();
+        assert_eq!(refined, vec![
+            (IntInterval::new(0, 0), vec![Some(2), None]),
+            (IntInterval::new(1, 2), vec![Some(2), Some(2)]),
+            (IntInterval::new(3, 4), vec![Some(2), Some(2)]),
+            (IntInterval::new(5, 5), vec![Some(2), None]),
+            (IntInterval::new(6, 8), vec![Some(4), Some(4)]),
+        ]);
+    }
+
+    #[test]
+    fn test_binned_interval_iter_32_and_unsigned_64_bits() {
+        let map: BTreeMap<I32Interval, i32> = vec![
+            (I32Interval::new(-7, -2), 1),
+            (I32Interval::new(3, 4), 3),
+            (I32Interval::new(9, 12), -1),
+        ]
+        .into_iter()
+        .collect();
+
+        // interval coordinates         | value
+        // -7 -6 -5 | -4 -3 -2 |        | +1
+        //          |          | 3 4    | +3
+        //          |          |        | 9 10 11 | 12  | -1
+        let actual: Vec<(I32Interval, i32)> = map
+            .iter()
+            .into_binned_interval_iter(
+                5,
+                AggregateOp::Sum,
+                Box::new(|(&interval, &val)| (interval, val)),
+            )
+            .collect();
+        assert_eq!(actual, vec![
+            (I32Interval::new(-10, -6), 2),
+            (I32Interval::new(-5, -1), 4),
+            (I32Interval::new(0, 4), 6),
+            (I32Interval::new(5, 9), -1),
+            (I32Interval::new(10, 14), -3),
+        ]);
+
+        // binning natively in i32 agrees with widening the intervals to i64
+        let widened: Vec<(I64Interval, i32)> = map
+            .iter()
+            .into_binned_interval_iter_by(5, AggregateOp::Sum, |(&i, &v)| {
+                let (start, end) = i.get_start_and_end();
+                (I64Interval::new(start as i64, end as i64), v)
+            })
+            .collect();
+        assert_eq!(
+            actual
+                .iter()
+                .map(|&(i, v)| {
+                    let (start, end) = i.get_start_and_end();
+                    (I64Interval::new(start as i64, end as i64), v)
+                })
+                .collect::<Vec<(I64Interval, i32)>>(),
+            widened
+        );
+
+        let base: u64 = 1_000_000_000_000;
+        let map: BTreeMap<U64Interval, f64> = vec![
+            (U64Interval::new(base, base + 9), 1.),
+            (U64Interval::new(base + 15, base + 15), 2.),
+        ]
+        .into_iter()
+        .collect();
+        let actual: Vec<(U64Interval, f64)> = map
+            .iter()
+            .into_binned_interval_iter_by(10, AggregateOp::Max, |(&i, &v)| {
+                (i, v)
+            })
+            .collect();
+        assert_eq!(actual, vec![
+            (U64Interval::new(base, base + 9), 1.),
+            (U64Interval::new(base + 10, base + 19), 2.),
+        ]);
+    }
+
+    #[test]
+    fn test_binned_interval_iter_by() {
+        let map: BTreeMap<I64Interval, i32> = vec![
+            (I64Interval::new(-3, 1), 2),
+            (I64Interval::new(2, 7), 1),
+            (I64Interval::new(12, 20), -1),
+        ]
+        .into_iter()
+        .collect();
+
+        for &op in [AggregateOp::Max, AggregateOp::Min, AggregateOp::Sum].iter()
+        {
+            let boxed: Vec<(I64Interval, i32)> = map
+                .iter()
+                .into_binned_interval_iter(
+                    4,
+                    op,
+                    Box::new(|(&interval, &val)| (interval, val)),
+                )
+                .collect();
+            let by_closure: Vec<(I64Interval, i32)> = map
+                .iter()
+                .into_binned_interval_iter_by(4, op, |(&interval, &val)| {
+                    (interval, val)
+                })
+                .collect();
+            assert_eq!(by_closure, boxed);
+
+            let by_closure_with_offset: Vec<(I64Interval, i32)> =
+                BinnedIntervalIter::with_offset_by(
+                    map.iter(),
+                    4,
+                    1,
+                    op,
+                    |(&interval, &val)| (interval, val),
+                )
+                .collect();
+            let boxed_with_offset: Vec<(I64Interval, i32)> = map
+                .iter()
+                .into_binned_interval_iter_with_offset(
+                    4,
+                    1,
+                    op,
+                    Box::new(|(&interval, &val)| (interval, val)),
+                )
+                .collect();
+            assert_eq!(by_closure_with_offset, boxed_with_offset);
+        }
+    }
+
     #[test]
     fn test_common_refinement_zip() {
         let bin_size = 3;
@@ -508,4 +1008,62 @@ mod tests {
         ];
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_common_refinement_zip_heterogeneous_iters() {
+        let bin_size = 3;
+        let mut map1 = IntegerIntervalMap::new();
+        map1.aggregate(I64Interval::new(-1, 4), 2);
+        map1.aggregate(I64Interval::new(6, 8), 4);
+        map1.aggregate(I64Interval::new(4, 7), 1);
+
+        let mut map2 = IntegerIntervalMap::new();
+        map2.aggregate(I64Interval::new(1, 2), -2);
+        map2.aggregate(I64Interval::new(6, 8), 9);
+
+        // map1 binned            | map2
+        // -3 -2 -1 | 0 1 2 | ... | 1 2 | 6 7 8
+        //  2       | 6     | ... | -2  | 9
+        let actual: Vec<(I64Interval, Vec<Option<i32>>)> = map1
+            .iter()
+            .into_binned_interval_iter(
+                bin_size,
+                AggregateOp::Sum,
+                Box::new(|(&interval, &val)| (interval, val)),
+            )
+            .common_refinement_zip(map2.iter())
+            .collect();
+
+        let expected = vec![
+            (I64Interval::new(-3, -1), vec![Some(2), None]),
+            (I64Interval::new(0, 0), vec![Some(6), None]),
+            (I64Interval::new(1, 2), vec![Some(6), Some(-2)]),
+            (I64Interval::new(3, 5), vec![Some(6), None]),
+            (I64Interval::new(6, 8), vec![Some(14), Some(9)]),
+        ];
+        assert_eq!(actual, expected);
+
+        let actual: Vec<(I64Interval, Vec<Option<i32>>)> = map2
+            .iter()
+            .common_refinement_zip(map1.iter().into_binned_interval_iter(
+                bin_size,
+                AggregateOp::Sum,
+                Box::new(|(&interval, &val)| (interval, val)),
+            ))
+            .common_refinement_flat_zip(map1.iter())
+            .collect();
+
+        let expected = vec![
+            (I64Interval::new(-3, -2), vec![None, Some(2), None]),
+            (I64Interval::new(-1, -1), vec![None, Some(2), Some(2)]),
+            (I64Interval::new(0, 0), vec![None, Some(6), Some(2)]),
+            (I64Interval::new(1, 2), vec![Some(-2), Some(6), Some(2)]),
+            (I64Interval::new(3, 3), vec![None, Some(6), Some(2)]),
+            (I64Interval::new(4, 4), vec![None, Some(6), Some(3)]),
+            (I64Interval::new(5, 5), vec![None, Some(6), Some(1)]),
+            (I64Interval::new(6, 7), vec![Some(9), Some(14), Some(5)]),
+            (I64Interval::new(8, 8), vec![Some(9), Some(14), Some(4)]),
+        ];
+        assert_eq!(actual, expected);
+    }
 }
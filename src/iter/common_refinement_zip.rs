@@ -9,6 +9,7 @@ use crate::{
 use num::{Integer, Num, ToPrimitive};
 use std::{
     collections::BTreeSet,
+    fmt,
     marker::{PhantomData, Sized},
 };
 
@@ -21,71 +22,170 @@ where
         &self,
     ) -> Box<dyn Fn(<Self as Iterator>::Item) -> (P, V)>;
 
-    fn common_refinement_zip(
-        mut self,
-        mut other: Self,
-    ) -> CommonRefinementZipped<B, Self, X, P, V> {
-        let extractor = self.get_interval_value_extractor();
-        let mut intervals = Vec::new();
-        let mut values = Vec::new();
-        match self.next() {
-            None => {
-                intervals.push(None);
-                values.push(None);
-            }
-            Some(x) => {
-                let (interval, value) = extractor(x);
-                intervals.push(Some(interval));
-                values.push(Some(value));
-            }
-        }
-        match other.next() {
-            None => {
-                intervals.push(None);
-                values.push(None);
-            }
-            Some(x) => {
-                let (interval, value) = extractor(x);
-                intervals.push(Some(interval));
-                values.push(Some(value));
-            }
-        }
-        CommonRefinementZipped {
-            iters: vec![self, other],
-            intervals,
-            values,
-            extractor,
-            phantom: PhantomData,
-        }
+    /// The `other` iterator can be of a different type from `Self` as long as
+    /// its items can be extracted into the same `(P, V)` pairs.
+    fn common_refinement_zip<'a, J, Y>(
+        self,
+        other: J,
+    ) -> CommonRefinementZipped<'a, B, P, V>
+    where
+        Self: 'a,
+        J: CommonRefinementZip<B, Y, P, V> + 'a,
+        X: 'a,
+        Y: 'a,
+        P: 'a,
+        V: 'a, {
+        self.into_common_refinement_zipped()
+            .common_refinement_flat_zip(other)
     }
 
-    fn into_common_refinement_zipped(
-        mut self,
-    ) -> CommonRefinementZipped<B, Self, X, P, V> {
-        let extractor = self.get_interval_value_extractor();
-        let mut intervals = Vec::new();
-        let mut values = Vec::new();
-        match self.next() {
-            None => {
-                intervals.push(None);
-                values.push(None);
-            }
-            Some(x) => {
-                let (interval, value) = extractor(x);
-                intervals.push(Some(interval));
-                values.push(Some(value));
-            }
+    /// Same as `common_refinement_zip` except that the intervals are validated
+    /// and the resulting iterator yields an `Err` if any of the iterators
+    /// yields an empty interval or intervals that are not disjoint and
+    /// increasing, after which the iteration terminates.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     interval::{traits::Interval, IntInterval},
+    ///     iter::{CommonRefinementZip, RefinementError},
+    /// };
+    /// use std::collections::BTreeMap;
+    ///
+    /// // the two intervals in m1 overlap
+    /// let m1: BTreeMap<IntInterval<usize>, i32> =
+    ///     vec![(IntInterval::new(0, 5), 1), (IntInterval::new(3, 8), 2)]
+    ///         .into_iter()
+    ///         .collect();
+    /// let m2: BTreeMap<IntInterval<usize>, i32> =
+    ///     vec![(IntInterval::new(2, 6), 4)].into_iter().collect();
+    ///
+    /// let mut iter = m1.iter().try_common_refinement_zip(m2.iter());
+    /// assert_eq!(
+    ///     Some(Ok((IntInterval::new(0, 1), vec![Some(1), None]))),
+    ///     iter.next()
+    /// );
+    /// assert_eq!(
+    ///     Some(Ok((IntInterval::new(2, 5), vec![Some(1), Some(4)]))),
+    ///     iter.next()
+    /// );
+    /// assert_eq!(
+    ///     Some(Err(RefinementError::NotDisjointIncreasing {
+    ///         iter_index: 0
+    ///     })),
+    ///     iter.next()
+    /// );
+    /// assert_eq!(None, iter.next());
+    /// ```
+    fn try_common_refinement_zip<'a, J, Y>(
+        self,
+        other: J,
+    ) -> TryCommonRefinementZipped<'a, B, P, V>
+    where
+        Self: 'a,
+        J: CommonRefinementZip<B, Y, P, V> + 'a,
+        X: 'a,
+        Y: 'a,
+        P: 'a,
+        V: 'a, {
+        TryCommonRefinementZipped {
+            zipped: self.common_refinement_zip(other).into_validating(),
         }
-        CommonRefinementZipped {
-            iters: vec![self],
-            intervals,
-            values,
-            extractor,
+    }
+
+    fn into_common_refinement_zipped<'a>(
+        self,
+    ) -> CommonRefinementZipped<'a, B, P, V>
+    where
+        Self: 'a,
+        X: 'a,
+        P: 'a,
+        V: 'a, {
+        let mut zipped = CommonRefinementZipped {
+            iters: Vec::new(),
+            intervals: Vec::new(),
+            values: Vec::new(),
+            validate: false,
+            error: None,
             phantom: PhantomData,
+        };
+        zipped.push_iter(into_interval_value_iter(self));
+        zipped
+    }
+}
+
+/// The error returned by `TryCommonRefinementZipped` when one of the zipped
+/// iterators yields an interval that violates the requirement of being
+/// non-empty, disjoint and increasing.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RefinementError {
+    /// The iterator at `iter_index` yielded an empty interval.
+    EmptyInterval { iter_index: usize },
+    /// The iterator at `iter_index` yielded an interval that does not start
+    /// after the end of its preceding interval.
+    NotDisjointIncreasing { iter_index: usize },
+}
+
+impl fmt::Display for RefinementError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RefinementError::EmptyInterval {
+                iter_index,
+            } => write!(f, "iterator {} yielded an empty interval", iter_index),
+            RefinementError::NotDisjointIncreasing {
+                iter_index,
+            } => write!(
+                f,
+                "iterator {} yielded intervals that are not disjoint and \
+                 increasing",
+                iter_index
+            ),
         }
     }
 }
 
+impl std::error::Error for RefinementError {}
+
+type RefinementResult<P, V> = Result<(P, Vec<Option<V>>), RefinementError>;
+
+/// Checks that the `next` interval yielded by the iterator at `iter_index` is
+/// non-empty and starts after the end of the preceding interval, if any.
+fn check_next_interval<B, P>(
+    iter_index: usize,
+    prev_end: Option<B>,
+    next: &P,
+) -> Option<RefinementError>
+where
+    B: Copy + Num + Ord,
+    P: Interval<B>, {
+    if next.is_empty() {
+        Some(RefinementError::EmptyInterval {
+            iter_index,
+        })
+    } else if prev_end.is_some_and(|end| next.get_start() <= end) {
+        Some(RefinementError::NotDisjointIncreasing {
+            iter_index,
+        })
+    } else {
+        None
+    }
+}
+
+/// Applies the interval value extractor of the `iter` to each of its items,
+/// erasing the concrete type of the `iter` in the process.
+fn into_interval_value_iter<'a, B, I, X, P, V>(
+    iter: I,
+) -> Box<dyn Iterator<Item = (P, V)> + 'a>
+where
+    B: Copy + Num + Ord,
+    I: CommonRefinementZip<B, X, P, V> + 'a,
+    X: 'a,
+    P: Clone + Interval<B> + for<'b> Intersect<&'b P, Option<P>> + 'a,
+    V: 'a, {
+    let extractor = iter.get_interval_value_extractor();
+    Box::new(iter.map(extractor))
+}
+
 /// # Example
 /// ```
 /// use math::{
@@ -168,118 +268,50 @@ where
 /// together with the associated values.
 ///
 /// # Fields
-/// * `iters`: the list of zipped iterators.
+/// * `iters`: the list of zipped iterators, each of which has been mapped to
+///   yield `(interval, value)` pairs by the extractor of the original iterator.
+///   The iterators are boxed so that iterators of different types can be zipped
+///   together.
 /// * `intervals`: the intervals assocaited with each iterator for the current
 ///   pass.
 /// * `values`: the values associated with each iterator for the current pass.
-/// * `extractor`: a function that extracts a tuple of (interval, value) from
-///   each of the items yielded from the iterators.
-pub struct CommonRefinementZipped<B, I, X, P, V>
+/// * `validate`: whether the intervals received from the iterators are checked
+///   to be non-empty, disjoint and increasing, which is only the case for
+///   `TryCommonRefinementZipped`.
+/// * `error`: the error to be returned in the next pass, if `validate` is set
+///   and an invalid interval has been received from one of the iterators.
+pub struct CommonRefinementZipped<'a, B, P, V>
 where
     B: Copy + Num + Ord,
-    I: Iterator<Item = X> + Sized,
     P: Clone + Interval<B> + for<'b> Intersect<&'b P, Option<P>>, {
-    iters: Vec<I>,
+    iters: Vec<Box<dyn Iterator<Item = (P, V)> + 'a>>,
     intervals: Vec<Option<P>>,
     values: Vec<Option<V>>,
-    extractor: Box<dyn Fn(X) -> (P, V)>,
+    validate: bool,
+    error: Option<RefinementError>,
     phantom: PhantomData<B>,
 }
 
-impl<B, I, X, P, V> Iterator for CommonRefinementZipped<B, I, X, P, V>
+/// The intervals from the zipped iterators are assumed to be non-empty,
+/// disjoint and increasing without being checked. Use
+/// `try_common_refinement_zip` to have invalid intervals reported as errors.
+impl<'a, B, P, V> Iterator for CommonRefinementZipped<'a, B, P, V>
 where
     B: Copy + Num + Ord,
-    I: Iterator<Item = X> + Sized,
     P: Clone + Interval<B> + for<'b> Intersect<&'b P, Option<P>>,
     V: Clone,
 {
     type Item = (P, Vec<Option<V>>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let starts: BTreeSet<B> = self
-            .intervals
-            .iter()
-            .filter_map(|i| i.clone().and_then(|i| Some(i.get_start())))
-            .collect();
-
-        let ends: BTreeSet<B> = self
-            .intervals
-            .iter()
-            .filter_map(|i| i.clone().and_then(|i| Some(i.get_end())))
-            .collect();
-
-        let mut starts_iter = starts.iter();
-        let min_start = match starts_iter.next() {
-            // if all intervals are empty, it means that all the iterators have
-            // been exhausted
-            None => return None,
-            Some(&a) => a,
-        };
-        let second_min_start = starts_iter.next();
-        let min_end = *ends.iter().next().unwrap();
-
-        let min_refinement = match second_min_start {
-            Some(&second_min_start) => {
-                if second_min_start <= min_end {
-                    P::from_boundaries(min_start, second_min_start - B::one())
-                } else {
-                    P::from_boundaries(min_start, min_end)
-                }
-            }
-            None => P::from_boundaries(min_start, min_end),
-        };
-
-        let mut refinement_values = Vec::new();
-        for ((interval, iter), v) in self
-            .intervals
-            .iter_mut()
-            .zip(self.iters.iter_mut())
-            .zip(self.values.iter_mut())
-        {
-            match interval {
-                Some(i) => {
-                    if i.has_non_empty_intersection_with(&min_refinement) {
-                        refinement_values.push((*v).clone());
-
-                        // subtract the min_refinement from the interval
-                        // min_start <= i.get_start() <= min_end <= i.get_end()
-                        let remainder = P::from_boundaries(
-                            min_refinement.get_end() + B::one(),
-                            i.get_end(),
-                        );
-                        if remainder.is_empty() {
-                            match iter.next() {
-                                None => {
-                                    *interval = None;
-                                    *v = None;
-                                }
-                                Some(x) => {
-                                    let (new_interval, new_val) =
-                                        (self.extractor)(x);
-                                    *interval = Some(new_interval);
-                                    *v = Some(new_val);
-                                }
-                            }
-                        } else {
-                            *interval = Some(remainder);
-                        }
-                    } else {
-                        refinement_values.push(None);
-                    }
-                }
-                None => {
-                    refinement_values.push(None);
-                }
-            }
-        }
-        Some((min_refinement, refinement_values))
+        // no error is recorded without validation
+        self.try_next().and_then(Result::ok)
     }
 }
 
-impl<B, I, X, P, V> CommonRefinementZipped<B, I, X, P, V>
+impl<'a, B, P, V> CommonRefinementZipped<'a, B, P, V>
 where
     B: Copy + Num + Ord,
-    I: Iterator<Item = X> + Sized,
     P: Clone + Interval<B> + for<'b> Intersect<&'b P, Option<P>>,
 {
     /// ```
@@ -350,30 +382,554 @@ where
     /// );
     /// assert_eq!(None, iter.next());
     /// ```
-    pub fn common_refinement_flat_zip(
+    pub fn common_refinement_flat_zip<J, Y>(
         mut self,
-        mut other: I,
-    ) -> CommonRefinementZipped<B, I, X, P, V>
+        other: J,
+    ) -> CommonRefinementZipped<'a, B, P, V>
+    where
+        J: CommonRefinementZip<B, Y, P, V> + 'a,
+        Y: 'a,
+        P: 'a,
+        V: 'a, {
+        self.push_iter(into_interval_value_iter(other));
+        self
+    }
+
+    /// Zips an arbitrary number of iterators of the same type at once, which
+    /// is equivalent to zipping the first two with `common_refinement_zip`
+    /// followed by `common_refinement_flat_zip` for each of the rest. The
+    /// values are in the order of the `iters`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     interval::{traits::Interval, IntInterval},
+    ///     iter::CommonRefinementZipped,
+    /// };
+    /// use std::collections::BTreeMap;
+    ///
+    /// let maps: Vec<BTreeMap<IntInterval<usize>, i32>> = vec![
+    ///     vec![(IntInterval::new(0, 3), 1)].into_iter().collect(),
+    ///     vec![(IntInterval::new(2, 5), 2)].into_iter().collect(),
+    ///     vec![(IntInterval::new(3, 3), 3)].into_iter().collect(),
+    /// ];
+    /// let refined: Vec<(IntInterval<usize>, Vec<Option<i32>>)> =
+    ///     CommonRefinementZipped::from_iters(
+    ///         maps.iter().map(|m| m.iter()).collect(),
+    ///     )
+    ///     .collect();
+    /// assert_eq!(refined, vec![
+    ///     (IntInterval::new(0, 1), vec![Some(1), None, None]),
+    ///     (IntInterval::new(2, 2), vec![Some(1), Some(2), None]),
+    ///     (IntInterval::new(3, 3), vec![Some(1), Some(2), Some(3)]),
+    ///     (IntInterval::new(4, 5), vec![None, Some(2), None]),
+    /// ]);
+    /// ```
+    pub fn from_iters<I, X>(
+        iters: Vec<I>,
+    ) -> CommonRefinementZipped<'a, B, P, V>
+    where
+        I: CommonRefinementZip<B, X, P, V> + 'a,
+        X: 'a,
+        P: 'a,
+        V: 'a, {
+        let mut zipped = CommonRefinementZipped {
+            iters: Vec::with_capacity(iters.len()),
+            intervals: Vec::with_capacity(iters.len()),
+            values: Vec::with_capacity(iters.len()),
+            validate: false,
+            error: None,
+            phantom: PhantomData,
+        };
+        for iter in iters {
+            zipped.push_iter(into_interval_value_iter(iter));
+        }
+        zipped
+    }
+
+    /// Collapses the values of each common refinement into a single value by
+    /// applying `f` to the values from all the zipped iterators.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     interval::{traits::Interval, IntInterval},
+    ///     iter::CommonRefinementZip,
+    /// };
+    /// use std::collections::BTreeMap;
+    ///
+    /// let m1: BTreeMap<IntInterval<usize>, i32> =
+    ///     vec![(IntInterval::new(0, 5), 1), (IntInterval::new(8, 9), 3)]
+    ///         .into_iter()
+    ///         .collect();
+    /// let m2: BTreeMap<IntInterval<usize>, i32> =
+    ///     vec![(IntInterval::new(4, 8), 10)].into_iter().collect();
+    ///
+    /// let sums: Vec<(IntInterval<usize>, i32)> = m1
+    ///     .iter()
+    ///     .common_refinement_zip(m2.iter())
+    ///     .reduce_values(|values| values.iter().flatten().sum())
+    ///     .collect();
+    /// assert_eq!(sums, vec![
+    ///     (IntInterval::new(0, 3), 1),
+    ///     (IntInterval::new(4, 5), 11),
+    ///     (IntInterval::new(6, 7), 10),
+    ///     (IntInterval::new(8, 8), 13),
+    ///     (IntInterval::new(9, 9), 3),
+    /// ]);
+    /// ```
+    pub fn reduce_values<W, F>(
+        self,
+        f: F,
+    ) -> impl Iterator<Item = (P, W)> + 'a
     where
-        I: Iterator<Item = X> + Sized, {
-        match other.next() {
+        F: Fn(&[Option<V>]) -> W + 'a,
+        B: 'a,
+        P: 'a,
+        V: Clone + 'a, {
+        self.map(move |(interval, values)| (interval, f(&values)))
+    }
+
+    fn push_iter(&mut self, mut iter: Box<dyn Iterator<Item = (P, V)> + 'a>) {
+        match iter.next() {
             None => {
                 self.intervals.push(None);
                 self.values.push(None);
             }
-            Some(x) => {
-                let (i, v) = (self.extractor)(x);
-                self.intervals.push(Some(i.clone()));
+            Some((i, v)) => {
+                if self.validate && self.error.is_none() {
+                    self.error =
+                        check_next_interval(self.iters.len(), None, &i);
+                }
+                self.intervals.push(Some(i));
                 self.values.push(Some(v));
             }
         }
-        self.iters.push(other);
-        CommonRefinementZipped {
-            iters: self.iters,
-            intervals: self.intervals,
-            values: self.values,
-            extractor: self.extractor,
-            phantom: PhantomData,
+        self.iters.push(iter);
+    }
+
+    /// Turns on the validation of the intervals, including the first interval
+    /// of each of the iterators zipped so far.
+    fn into_validating(mut self) -> Self {
+        self.validate = true;
+        if self.error.is_none() {
+            self.error =
+                self.intervals.iter().enumerate().find_map(|(k, interval)| {
+                    interval
+                        .as_ref()
+                        .and_then(|i| check_next_interval(k, None, i))
+                });
+        }
+        self
+    }
+
+    /// Returns the next common refinement, or an error if an invalid interval
+    /// has been received from one of the iterators. The iteration terminates
+    /// after an error is returned.
+    fn try_next(&mut self) -> Option<RefinementResult<P, V>>
+    where
+        V: Clone, {
+        if let Some(err) = self.error.take() {
+            for interval in self.intervals.iter_mut() {
+                *interval = None;
+            }
+            return Some(Err(err));
+        }
+
+        let starts: BTreeSet<B> = self
+            .intervals
+            .iter()
+            .filter_map(|i| i.clone().map(|i| i.get_start()))
+            .collect();
+
+        let ends: BTreeSet<B> = self
+            .intervals
+            .iter()
+            .filter_map(|i| i.clone().map(|i| i.get_end()))
+            .collect();
+
+        let mut starts_iter = starts.iter();
+        let (min_start, min_end) =
+            match (starts_iter.next(), ends.iter().next()) {
+                (Some(&start), Some(&end)) => (start, end),
+                // if all intervals are None, it means that all the iterators
+                // have been exhausted, and the exhausted
+                // iterators are skipped otherwise
+                _ => return None,
+            };
+        let second_min_start = starts_iter.next();
+
+        let min_refinement = match second_min_start {
+            Some(&second_min_start) => {
+                if second_min_start <= min_end {
+                    P::from_boundaries(min_start, second_min_start - B::one())
+                } else {
+                    P::from_boundaries(min_start, min_end)
+                }
+            }
+            None => P::from_boundaries(min_start, min_end),
+        };
+
+        let mut refinement_values = Vec::new();
+        for (iter_index, ((interval, iter), v)) in self
+            .intervals
+            .iter_mut()
+            .zip(self.iters.iter_mut())
+            .zip(self.values.iter_mut())
+            .enumerate()
+        {
+            match interval {
+                Some(i)
+                    if i.has_non_empty_intersection_with(&min_refinement) =>
+                {
+                    refinement_values.push((*v).clone());
+
+                    // subtract the min_refinement from the interval
+                    // min_start <= i.get_start() <= min_end <= i.get_end()
+                    let remainder = P::from_boundaries(
+                        min_refinement.get_end() + B::one(),
+                        i.get_end(),
+                    );
+                    if remainder.is_empty() {
+                        match iter.next() {
+                            None => {
+                                *interval = None;
+                                *v = None;
+                            }
+                            Some((new_interval, new_val)) => {
+                                // The current refinement is unaffected by
+                                // an invalid new interval, so the error is
+                                // returned in the next iteration.
+                                if self.validate && self.error.is_none() {
+                                    self.error = check_next_interval(
+                                        iter_index,
+                                        Some(i.get_end()),
+                                        &new_interval,
+                                    );
+                                }
+                                *interval = Some(new_interval);
+                                *v = Some(new_val);
+                            }
+                        }
+                    } else {
+                        *interval = Some(remainder);
+                    }
+                }
+                _ => {
+                    refinement_values.push(None);
+                }
+            }
         }
+        Some(Ok((min_refinement, refinement_values)))
+    }
+}
+
+/// The validating counterpart of `CommonRefinementZipped` that yields a
+/// `RefinementError` if any of the zipped iterators yields an empty interval or
+/// intervals that are not disjoint and increasing. The iteration terminates
+/// after the error is returned.
+pub struct TryCommonRefinementZipped<'a, B, P, V>
+where
+    B: Copy + Num + Ord,
+    P: Clone + Interval<B> + for<'b> Intersect<&'b P, Option<P>>, {
+    zipped: CommonRefinementZipped<'a, B, P, V>,
+}
+
+impl<'a, B, P, V> TryCommonRefinementZipped<'a, B, P, V>
+where
+    B: Copy + Num + Ord,
+    P: Clone + Interval<B> + for<'b> Intersect<&'b P, Option<P>>,
+{
+    pub fn common_refinement_flat_zip<J, Y>(
+        self,
+        other: J,
+    ) -> TryCommonRefinementZipped<'a, B, P, V>
+    where
+        J: CommonRefinementZip<B, Y, P, V> + 'a,
+        Y: 'a,
+        P: 'a,
+        V: 'a, {
+        TryCommonRefinementZipped {
+            zipped: self.zipped.common_refinement_flat_zip(other),
+        }
+    }
+}
+
+impl<'a, B, P, V> Iterator for TryCommonRefinementZipped<'a, B, P, V>
+where
+    B: Copy + Num + Ord,
+    P: Clone + Interval<B> + for<'b> Intersect<&'b P, Option<P>>,
+    V: Clone,
+{
+    type Item = RefinementResult<P, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.zipped.try_next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        interval::IntInterval,
+        iter::{CommonRefinementZip, CommonRefinementZipped, RefinementError},
+    };
+    use std::collections::BTreeMap;
+
+    type Refined = (IntInterval<i64>, Vec<Option<i32>>);
+
+    #[test]
+    fn test_try_common_refinement_zip_overlapping() {
+        let m1: BTreeMap<IntInterval<i64>, i32> = vec![
+            (IntInterval::new(0, 3), 1),
+            (IntInterval::new(6, 9), 2),
+            (IntInterval::new(8, 12), 3),
+        ]
+        .into_iter()
+        .collect();
+        let m2: BTreeMap<IntInterval<i64>, i32> =
+            vec![(IntInterval::new(2, 7), 4), (IntInterval::new(10, 11), 5)]
+                .into_iter()
+                .collect();
+
+        let actual: Vec<Result<Refined, RefinementError>> =
+            m1.iter().try_common_refinement_zip(m2.iter()).collect();
+        assert_eq!(actual, vec![
+            Ok((IntInterval::new(0, 1), vec![Some(1), None])),
+            Ok((IntInterval::new(2, 3), vec![Some(1), Some(4)])),
+            Ok((IntInterval::new(4, 5), vec![None, Some(4)])),
+            Ok((IntInterval::new(6, 7), vec![Some(2), Some(4)])),
+            Ok((IntInterval::new(8, 9), vec![Some(2), None])),
+            Err(RefinementError::NotDisjointIncreasing {
+                iter_index: 0
+            }),
+        ]);
+
+        // the overlapping iterator is the second one
+        let actual: Vec<Result<Refined, RefinementError>> =
+            m2.iter().try_common_refinement_zip(m1.iter()).collect();
+        assert_eq!(
+            actual.last(),
+            Some(&Err(RefinementError::NotDisjointIncreasing {
+                iter_index: 1
+            }))
+        );
+        assert_eq!(actual.iter().filter(|r| r.is_err()).count(), 1);
+    }
+
+    #[test]
+    fn test_try_common_refinement_zip_empty_interval() {
+        let m1: BTreeMap<IntInterval<i64>, i32> =
+            vec![(IntInterval::new(0, 3), 1)].into_iter().collect();
+        let m2: BTreeMap<IntInterval<i64>, i32> =
+            vec![(IntInterval::new(0, 1), 2)].into_iter().collect();
+        let m3: BTreeMap<IntInterval<i64>, i32> =
+            vec![(IntInterval::new(5, 4), 3)].into_iter().collect();
+
+        let mut iter = m1
+            .iter()
+            .try_common_refinement_zip(m2.iter())
+            .common_refinement_flat_zip(m3.iter());
+        assert_eq!(
+            iter.next(),
+            Some(Err(RefinementError::EmptyInterval {
+                iter_index: 2
+            }))
+        );
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_try_common_refinement_zip_valid() {
+        let m1: BTreeMap<IntInterval<i64>, i32> =
+            vec![(IntInterval::new(0, 5), 1), (IntInterval::new(8, 9), 2)]
+                .into_iter()
+                .collect();
+        let m2: BTreeMap<IntInterval<i64>, i32> =
+            vec![(IntInterval::new(3, 8), 4)].into_iter().collect();
+
+        let expected: Vec<Refined> =
+            m1.iter().common_refinement_zip(m2.iter()).collect();
+        let actual: Result<Vec<Refined>, _> =
+            m1.iter().try_common_refinement_zip(m2.iter()).collect();
+        assert_eq!(actual, Ok(expected));
+    }
+
+    #[test]
+    fn test_common_refinement_zip_with_empty_iters() {
+        let empty = BTreeMap::<IntInterval<i64>, i32>::new();
+        let m1: BTreeMap<IntInterval<i64>, i32> =
+            vec![(IntInterval::new(0, 5), 1), (IntInterval::new(8, 9), 2)]
+                .into_iter()
+                .collect();
+        let m2: BTreeMap<IntInterval<i64>, i32> =
+            vec![(IntInterval::new(3, 8), 4)].into_iter().collect();
+
+        assert_eq!(
+            empty.iter().common_refinement_zip(empty.iter()).next(),
+            None
+        );
+        assert_eq!(
+            empty
+                .iter()
+                .common_refinement_zip(empty.iter())
+                .common_refinement_flat_zip(empty.iter())
+                .next(),
+            None
+        );
+
+        let two_way: Vec<Refined> =
+            m1.iter().common_refinement_zip(m2.iter()).collect();
+        assert_eq!(two_way, vec![
+            (IntInterval::new(0, 2), vec![Some(1), None]),
+            (IntInterval::new(3, 5), vec![Some(1), Some(4)]),
+            (IntInterval::new(6, 7), vec![None, Some(4)]),
+            (IntInterval::new(8, 8), vec![Some(2), Some(4)]),
+            (IntInterval::new(9, 9), vec![Some(2), None]),
+        ]);
+
+        // inserts a None at the `position` of each list of values
+        let with_none_at = |position: usize| -> Vec<Refined> {
+            two_way
+                .iter()
+                .map(|(interval, values)| {
+                    let mut values = values.clone();
+                    values.insert(position, None);
+                    (*interval, values)
+                })
+                .collect()
+        };
+
+        let actual: Vec<Refined> = empty
+            .iter()
+            .common_refinement_zip(m1.iter())
+            .common_refinement_flat_zip(m2.iter())
+            .collect();
+        assert_eq!(actual, with_none_at(0));
+
+        let actual: Vec<Refined> = m1
+            .iter()
+            .common_refinement_zip(empty.iter())
+            .common_refinement_flat_zip(m2.iter())
+            .collect();
+        assert_eq!(actual, with_none_at(1));
+
+        let actual: Vec<Refined> = m1
+            .iter()
+            .common_refinement_zip(m2.iter())
+            .common_refinement_flat_zip(empty.iter())
+            .collect();
+        assert_eq!(actual, with_none_at(2));
+
+        let actual: Result<Vec<Refined>, _> = m1
+            .iter()
+            .try_common_refinement_zip(empty.iter())
+            .common_refinement_flat_zip(m2.iter())
+            .collect();
+        assert_eq!(actual, Ok(with_none_at(1)));
+
+        // the iterators are exhausted at different times
+        let m3: BTreeMap<IntInterval<i64>, i32> =
+            vec![(IntInterval::new(20, 21), 7)].into_iter().collect();
+        let actual: Vec<Refined> = m3
+            .iter()
+            .common_refinement_zip(empty.iter())
+            .common_refinement_flat_zip(m2.iter())
+            .collect();
+        assert_eq!(actual, vec![
+            (IntInterval::new(3, 8), vec![None, None, Some(4)]),
+            (IntInterval::new(20, 21), vec![Some(7), None, None]),
+        ]);
+    }
+
+    #[test]
+    fn test_from_iters() {
+        let maps: Vec<BTreeMap<IntInterval<i64>, i32>> = vec![
+            vec![(IntInterval::new(0, 5), 1), (IntInterval::new(8, 9), 2)],
+            vec![(IntInterval::new(3, 8), 4)],
+            vec![(IntInterval::new(-2, 1), 5), (IntInterval::new(5, 6), 6)],
+            vec![(IntInterval::new(9, 12), 7)],
+        ]
+        .into_iter()
+        .map(|v| v.into_iter().collect())
+        .collect();
+
+        let chained: Vec<Refined> = maps[0]
+            .iter()
+            .common_refinement_zip(maps[1].iter())
+            .common_refinement_flat_zip(maps[2].iter())
+            .common_refinement_flat_zip(maps[3].iter())
+            .collect();
+        let actual: Vec<Refined> = CommonRefinementZipped::from_iters(
+            maps.iter().map(|m| m.iter()).collect(),
+        )
+        .collect();
+        assert_eq!(actual, chained);
+        assert_eq!(actual, vec![
+            (IntInterval::new(-2, -1), vec![None, None, Some(5), None]),
+            (IntInterval::new(0, 1), vec![Some(1), None, Some(5), None]),
+            (IntInterval::new(2, 2), vec![Some(1), None, None, None]),
+            (IntInterval::new(3, 4), vec![Some(1), Some(4), None, None]),
+            (IntInterval::new(5, 5), vec![
+                Some(1),
+                Some(4),
+                Some(6),
+                None
+            ]),
+            (IntInterval::new(6, 6), vec![None, Some(4), Some(6), None]),
+            (IntInterval::new(7, 7), vec![None, Some(4), None, None]),
+            (IntInterval::new(8, 8), vec![Some(2), Some(4), None, None]),
+            (IntInterval::new(9, 9), vec![Some(2), None, None, Some(7)]),
+            (IntInterval::new(10, 12), vec![None, None, None, Some(7)]),
+        ]);
+
+        // owning iterators
+        let actual: Vec<Refined> = CommonRefinementZipped::from_iters(
+            maps.clone().into_iter().map(|m| m.into_iter()).collect(),
+        )
+        .collect();
+        assert_eq!(actual, chained);
+
+        let single: Vec<Refined> =
+            CommonRefinementZipped::from_iters(vec![maps[0].iter()]).collect();
+        assert_eq!(single, vec![
+            (IntInterval::new(0, 5), vec![Some(1)]),
+            (IntInterval::new(8, 9), vec![Some(2)]),
+        ]);
+
+        let no_iters: Vec<
+            std::collections::btree_map::Iter<IntInterval<i64>, i32>,
+        > = Vec::new();
+        let none: Vec<Refined> =
+            CommonRefinementZipped::from_iters(no_iters).collect();
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_common_refinement_zip_does_not_validate() {
+        let m1: BTreeMap<IntInterval<i64>, i32> =
+            vec![(IntInterval::new(0, 5), 1), (IntInterval::new(3, 8), 2)]
+                .into_iter()
+                .collect();
+        let m2: BTreeMap<IntInterval<i64>, i32> =
+            vec![(IntInterval::new(2, 6), 4)].into_iter().collect();
+        let actual: Vec<Refined> =
+            m1.iter().common_refinement_zip(m2.iter()).collect();
+        // the overlapping intervals are refined as is without validation
+        assert_eq!(actual, vec![
+            (IntInterval::new(0, 1), vec![Some(1), None]),
+            (IntInterval::new(2, 5), vec![Some(1), Some(4)]),
+            (IntInterval::new(3, 5), vec![Some(2), None]),
+            (IntInterval::new(6, 6), vec![Some(2), Some(4)]),
+            (IntInterval::new(7, 8), vec![Some(2), None]),
+        ]);
+        let errors: Vec<RefinementError> = m1
+            .iter()
+            .try_common_refinement_zip(m2.iter())
+            .filter_map(Result::err)
+            .collect();
+        assert_eq!(errors, vec![RefinementError::NotDisjointIncreasing {
+            iter_index: 0
+        }]);
     }
 }
@@ -42,6 +42,43 @@ impl<I: Iterator> Iterator for ConcatenatedIter<I> {
             },
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iters
+            .get(self.current_iter_index..)
+            .unwrap_or(&[])
+            .iter()
+            .map(|iter| iter.size_hint())
+            .fold((0, Some(0)), |(lower, upper), (l, u)| {
+                (
+                    lower.saturating_add(l),
+                    upper
+                        .and_then(|upper| u.and_then(|u| upper.checked_add(u))),
+                )
+            })
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedIterator for ConcatenatedIter<I> {
+    /// Consumes the last inner iterator from the back and drops it once it is
+    /// exhausted. When the backward and the forward cursors reach the same
+    /// inner iterator, the inner iterator itself keeps the two ends from
+    /// crossing each other.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.iters.len() <= self.current_iter_index {
+            return None;
+        }
+        match self.iters.last_mut() {
+            None => None,
+            Some(iter) => match iter.next_back() {
+                Some(x) => Some(x),
+                None => {
+                    self.iters.pop();
+                    self.next_back()
+                }
+            },
+        }
+    }
 }
 
 pub trait IntoConcatIter: Iterator + Sized {
@@ -126,4 +163,85 @@ mod tests {
         assert_eq!(iter.next(), Some((&13, &14)));
         assert_eq!(iter.next(), Some((&15, &16)));
     }
+
+    #[test]
+    fn test_size_hint() {
+        let arr1 = [0, 1, 2];
+        let arr2 = [3, 4];
+        let arr3 = [5, 6];
+        let mut concat_iter =
+            ConcatenatedIter::from(vec![arr1.iter(), arr2.iter(), arr3.iter()]);
+        assert_eq!(concat_iter.size_hint(), (7, Some(7)));
+        concat_iter.next();
+        assert_eq!(concat_iter.size_hint(), (6, Some(6)));
+        for _ in 0..3 {
+            concat_iter.next();
+        }
+        assert_eq!(concat_iter.size_hint(), (3, Some(3)));
+        for _ in 0..3 {
+            concat_iter.next();
+        }
+        assert_eq!(concat_iter.size_hint(), (0, Some(0)));
+        assert_eq!(concat_iter.next(), None);
+        assert_eq!(concat_iter.size_hint(), (0, Some(0)));
+
+        let collected: Vec<&i32> = arr1
+            .iter()
+            .into_concat_iter(arr2.iter())
+            .concat_iter(arr3.iter())
+            .collect();
+        assert_eq!(collected, vec![&0, &1, &2, &3, &4, &5, &6]);
+
+        // the upper bound is unknown if any of the upper bounds is unknown
+        let keep = |_: &&i32| true;
+        let filtered = ConcatenatedIter::from(vec![
+            arr1.iter().filter(keep),
+            arr2.iter().filter(keep),
+        ]);
+        assert_eq!(filtered.size_hint(), (0, Some(5)));
+        let unbounded = ConcatenatedIter::from(vec![
+            Box::new(0..3) as Box<dyn Iterator<Item = i32>>,
+            Box::new(0..),
+        ]);
+        assert_eq!(unbounded.size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn test_next_back() {
+        let arr1 = [0, 1, 2];
+        let arr2 = [3, 4];
+        let arr3 = [5, 6];
+        let mut concat_iter =
+            ConcatenatedIter::from(vec![arr1.iter(), arr2.iter(), arr3.iter()]);
+        assert_eq!(concat_iter.next_back(), Some(&6));
+        assert_eq!(concat_iter.next(), Some(&0));
+        assert_eq!(concat_iter.next_back(), Some(&5));
+        assert_eq!(concat_iter.next_back(), Some(&4));
+        assert_eq!(concat_iter.size_hint(), (3, Some(3)));
+        assert_eq!(concat_iter.next(), Some(&1));
+        assert_eq!(concat_iter.next_back(), Some(&3));
+        assert_eq!(concat_iter.next_back(), Some(&2));
+        assert_eq!(concat_iter.next(), None);
+        assert_eq!(concat_iter.next_back(), None);
+
+        let mut concat_iter =
+            ConcatenatedIter::from(vec![arr1.iter(), arr2.iter(), arr3.iter()]);
+        assert_eq!(concat_iter.next(), Some(&0));
+        assert_eq!(concat_iter.next(), Some(&1));
+        assert_eq!(concat_iter.next(), Some(&2));
+        assert_eq!(concat_iter.next(), Some(&3));
+        assert_eq!(concat_iter.next_back(), Some(&6));
+        assert_eq!(concat_iter.next_back(), Some(&5));
+        assert_eq!(concat_iter.next_back(), Some(&4));
+        assert_eq!(concat_iter.next_back(), None);
+        assert_eq!(concat_iter.next(), None);
+
+        let reversed: Vec<&i32> = arr1
+            .iter()
+            .into_concat_iter(arr2.iter())
+            .concat_iter(arr3.iter())
+            .rev()
+            .collect();
+        assert_eq!(reversed, vec![&6, &5, &4, &3, &2, &1, &0]);
+    }
 }
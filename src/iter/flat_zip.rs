@@ -41,9 +41,63 @@ impl<I: Iterator> Iterator for FlatZipIter<I> {
     }
 }
 
+pub trait IntoFlatZipLongestIter<I> {
+    fn flat_zip_longest(self, other: I) -> FlatZipLongestIter<I>;
+}
+
+/// Unlike `FlatZipIter`, which stops as soon as any of the iterators is
+/// exhausted, `FlatZipLongestIter` continues until all the iterators are
+/// exhausted, padding the exhausted ones with `None`.
+///
+/// # Example
+/// ```
+/// use math::iter::IntoFlatZipLongestIter;
+///
+/// let arr1 = vec![1, 2];
+/// let arr2 = vec![3];
+/// let mut iter = arr1.iter().flat_zip_longest(arr2.iter());
+/// assert_eq!(iter.next(), Some(vec![Some(&1), Some(&3)]));
+/// assert_eq!(iter.next(), Some(vec![Some(&2), None]));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub struct FlatZipLongestIter<I> {
+    iters: Vec<I>,
+}
+
+impl<I: Iterator> IntoFlatZipLongestIter<I> for I {
+    fn flat_zip_longest(self, other: I) -> FlatZipLongestIter<I> {
+        FlatZipLongestIter {
+            iters: vec![self, other],
+        }
+    }
+}
+
+impl<I: Iterator> IntoFlatZipLongestIter<I> for FlatZipLongestIter<I> {
+    fn flat_zip_longest(mut self, other: I) -> FlatZipLongestIter<I> {
+        self.iters.push(other);
+        FlatZipLongestIter {
+            iters: self.iters,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for FlatZipLongestIter<I> {
+    type Item = Vec<Option<<I as Iterator>::Item>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let items: Self::Item =
+            self.iters.iter_mut().map(|i| i.next()).collect();
+        if items.iter().all(|x| x.is_none()) {
+            None
+        } else {
+            Some(items)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::iter::flat_zip::IntoFlatZipIter;
+    use crate::iter::flat_zip::{IntoFlatZipIter, IntoFlatZipLongestIter};
 
     #[test]
     fn test_flat_zip() {
@@ -76,4 +130,34 @@ mod tests {
             assert_eq!(i1, i2);
         }
     }
+
+    #[test]
+    fn test_flat_zip_longest() {
+        let arr1 = [1, 2, 3];
+        let arr2 = [4, 5];
+        let arr3 = [7, 8, 9, 10];
+        let actual: Vec<Vec<Option<&i32>>> = arr1
+            .iter()
+            .flat_zip_longest(arr2.iter())
+            .flat_zip_longest(arr3.iter())
+            .collect();
+        assert_eq!(actual, vec![
+            vec![Some(&1), Some(&4), Some(&7)],
+            vec![Some(&2), Some(&5), Some(&8)],
+            vec![Some(&3), None, Some(&9)],
+            vec![None, None, Some(&10)],
+        ]);
+
+        // the truncating behavior of flat_zip is unchanged
+        assert_eq!(
+            arr1.iter()
+                .flat_zip(arr2.iter())
+                .flat_zip(arr3.iter())
+                .count(),
+            2
+        );
+
+        let empty: Vec<i32> = Vec::new();
+        assert_eq!(empty.iter().flat_zip_longest(empty.iter()).next(), None);
+    }
 }
@@ -1,5 +1,6 @@
 use std::{
-    collections::{HashMap, HashSet},
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
     hash::Hash,
     vec::IntoIter,
 };
@@ -15,16 +16,72 @@ pub trait IntoUnionZip<'a, K, M> {
 }
 
 /// Could be useful as the `init` argument in a `fold` operation.
+///
+/// The map and its keys are borrowed rather than cloned, and the resulting
+/// `UnionZipped` can be chained with `into_union_zip` to zip with further
+/// maps.
+///
+/// # Example
+/// ```
+/// use math::iter::{AsUnionZipped, IntoUnionZip};
+/// use std::collections::HashMap;
+///
+/// let maps: Vec<HashMap<i32, i32>> = vec![
+///     vec![(1, 10), (3, 23)].into_iter().collect(),
+///     vec![(0, 4), (1, 20)].into_iter().collect(),
+///     vec![(3, 7)].into_iter().collect(),
+/// ];
+/// let zipped = maps[1..]
+///     .iter()
+///     .fold(maps[0].as_union_zipped(), |zipped, m| {
+///         zipped.into_union_zip(m)
+///     });
+/// assert_eq!(zipped.into_iter().collect::<Vec<_>>(), vec![
+///     (0, vec![None, Some(&4), None]),
+///     (1, vec![Some(&10), Some(&20), None]),
+///     (3, vec![Some(&23), None, Some(&7)]),
+/// ]);
+/// ```
 pub trait AsUnionZipped<'a, K, M> {
     fn as_union_zipped(&'a self) -> UnionZipped<'a, K, M>;
 }
 
+/// The keys are borrowed from the zipped maps and are only cloned as they are
+/// yielded by the iterator.
 pub struct UnionZipped<'a, K, M> {
-    keys: Vec<K>,
+    keys: Vec<&'a K>,
     maps: Vec<&'a M>,
 }
 
-pub struct UnionZippedIter<'a, K, M, I: Iterator<Item = K>> {
+impl<'a, K, M> UnionZipped<'a, K, M> {
+    /// The sorted union of the keys of all the zipped maps.
+    ///
+    /// # Example
+    /// ```
+    /// use math::iter::UnionZip;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let m1: BTreeMap<i32, i32> = vec![(1, 10), (3, 23)].into_iter().collect();
+    /// let m2: BTreeMap<i32, i32> = vec![(0, 4), (1, 20)].into_iter().collect();
+    /// assert_eq!(m1.union_zip(&m2).keys(), &[&0, &1, &3]);
+    /// ```
+    pub fn keys(&self) -> &[&'a K] {
+        &self.keys
+    }
+}
+
+/// Only the references to the keys and maps are copied, so that the same
+/// zipped maps can be iterated more than once.
+impl<'a, K, M> Clone for UnionZipped<'a, K, M> {
+    fn clone(&self) -> Self {
+        UnionZipped {
+            keys: self.keys.clone(),
+            maps: self.maps.clone(),
+        }
+    }
+}
+
+pub struct UnionZippedIter<'a, K: 'a, M, I: Iterator<Item = &'a K>> {
     keys: I,
     maps: Vec<&'a M>,
 }
@@ -49,17 +106,17 @@ pub struct UnionZippedIter<'a, K, M, I: Iterator<Item = K>> {
 /// ```
 impl<K, V> UnionZip<K, HashMap<K, V>> for HashMap<K, V>
 where
-    K: Hash + Eq + Clone + Ord,
+    K: Hash + Eq + Ord,
 {
     fn union_zip<'a>(
         &'a self,
         other: &'a Self,
     ) -> UnionZipped<'a, K, HashMap<K, V>> {
-        let mut keys: Vec<K> = self
+        let mut keys: Vec<&K> = self
             .keys()
             .collect::<HashSet<&K>>()
             .union(&other.keys().collect::<HashSet<&K>>())
-            .map(|&k| k.clone())
+            .copied()
             .collect();
 
         keys.sort();
@@ -74,18 +131,18 @@ where
 impl<'a, K, V> IntoUnionZip<'a, K, HashMap<K, V>>
     for UnionZipped<'a, K, HashMap<K, V>>
 where
-    K: Hash + Eq + Clone + Ord,
+    K: Hash + Eq + Ord,
 {
     fn into_union_zip(
         self,
         other: &'a HashMap<K, V>,
     ) -> UnionZipped<'a, K, HashMap<K, V>> {
-        let mut keys: Vec<K> = self
+        let mut keys: Vec<&K> = self
             .keys
-            .iter()
+            .into_iter()
             .collect::<HashSet<&K>>()
             .union(&other.keys().collect::<HashSet<&K>>())
-            .map(|&k| k.clone())
+            .copied()
             .collect();
 
         keys.sort();
@@ -101,10 +158,10 @@ where
 
 impl<'a, K, V> AsUnionZipped<'a, K, HashMap<K, V>> for HashMap<K, V>
 where
-    K: Clone + Ord,
+    K: Ord,
 {
     fn as_union_zipped(&'a self) -> UnionZipped<'a, K, HashMap<K, V>> {
-        let mut keys: Vec<K> = self.keys().cloned().collect();
+        let mut keys: Vec<&K> = self.keys().collect();
         keys.sort();
         UnionZipped {
             keys,
@@ -115,11 +172,10 @@ where
 
 impl<'a, K, V> IntoIterator for UnionZipped<'a, K, HashMap<K, V>>
 where
-    K: Hash + Eq,
+    K: Hash + Eq + Clone,
 {
-    type IntoIter = UnionZippedIter<'a, K, HashMap<K, V>, IntoIter<K>>;
-    type Item =
-        <UnionZippedIter<'a, K, HashMap<K, V>, IntoIter<K>> as Iterator>::Item;
+    type IntoIter = UnionZippedIter<'a, K, HashMap<K, V>, IntoIter<&'a K>>;
+    type Item = (K, Vec<Option<&'a V>>);
 
     fn into_iter(self) -> Self::IntoIter {
         UnionZippedIter {
@@ -129,9 +185,10 @@ where
     }
 }
 
-impl<'a, K, V> Iterator for UnionZippedIter<'a, K, HashMap<K, V>, IntoIter<K>>
+impl<'a, K, V> Iterator
+    for UnionZippedIter<'a, K, HashMap<K, V>, IntoIter<&'a K>>
 where
-    K: Hash + Eq,
+    K: Hash + Eq + Clone,
 {
     type Item = (K, Vec<Option<&'a V>>);
 
@@ -142,21 +199,277 @@ where
                 let mapped: Vec<Option<&'a V>> = self
                     .maps
                     .iter()
-                    .map(|m| {
-                        if m.contains_key(&k) {
-                            Some(&m[&k])
-                        } else {
-                            None
-                        }
-                    })
+                    .map(|m| if m.contains_key(k) { Some(&m[k]) } else { None })
                     .collect();
 
+                Some((k.clone(), mapped))
+            }
+        }
+    }
+}
+
+/// `K` is the key type
+/// `M` is the map type that maps keys of type `K` to values
+pub trait IntersectionZip<K, M> {
+    fn intersection_zip<'a>(
+        &'a self,
+        other: &'a M,
+    ) -> IntersectionZipped<'a, K, M>;
+}
+
+pub trait IntoIntersectionZip<'a, K, M> {
+    fn into_intersection_zip(
+        self,
+        other: &'a M,
+    ) -> IntersectionZipped<'a, K, M>;
+}
+
+/// Unlike `UnionZipped`, only the keys present in all the maps are retained,
+/// so that every map is guaranteed to have a value for each key.
+pub struct IntersectionZipped<'a, K, M> {
+    keys: Vec<K>,
+    maps: Vec<&'a M>,
+}
+
+pub struct IntersectionZippedIter<'a, K, M, I: Iterator<Item = K>> {
+    keys: I,
+    maps: Vec<&'a M>,
+}
+
+/// Takes the sorted intersection of the two sets of keys for future iteration
+/// ```
+/// use math::iter::{IntersectionZip, IntoIntersectionZip};
+/// use std::collections::HashMap;
+/// let m1: HashMap<i32, i32> =
+///     vec![(1, 10), (3, 23), (4, 20)].into_iter().collect();
+/// let m2: HashMap<i32, i32> = vec![(0, 4), (1, 20), (4, 20), (9, 29)]
+///     .into_iter()
+///     .collect();
+/// let m3: HashMap<i32, i32> = vec![(1, 7), (3, 5)].into_iter().collect();
+///
+/// let mut iter = m1.intersection_zip(&m2).into_iter();
+/// assert_eq!(Some((1, vec![&10, &20])), iter.next());
+/// assert_eq!(Some((4, vec![&20, &20])), iter.next());
+/// assert_eq!(None, iter.next());
+///
+/// let mut iter = m1
+///     .intersection_zip(&m2)
+///     .into_intersection_zip(&m3)
+///     .into_iter();
+/// assert_eq!(Some((1, vec![&10, &20, &7])), iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+impl<K, V> IntersectionZip<K, HashMap<K, V>> for HashMap<K, V>
+where
+    K: Hash + Eq + Clone + Ord,
+{
+    fn intersection_zip<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> IntersectionZipped<'a, K, HashMap<K, V>> {
+        let mut keys: Vec<K> = self
+            .keys()
+            .filter(|k| other.contains_key(k))
+            .cloned()
+            .collect();
+
+        keys.sort();
+
+        IntersectionZipped {
+            keys,
+            maps: vec![&self, other],
+        }
+    }
+}
+
+impl<'a, K, V> IntoIntersectionZip<'a, K, HashMap<K, V>>
+    for IntersectionZipped<'a, K, HashMap<K, V>>
+where
+    K: Hash + Eq,
+{
+    fn into_intersection_zip(
+        self,
+        other: &'a HashMap<K, V>,
+    ) -> IntersectionZipped<'a, K, HashMap<K, V>> {
+        // the retained keys remain sorted
+        let keys: Vec<K> = self
+            .keys
+            .into_iter()
+            .filter(|k| other.contains_key(k))
+            .collect();
+
+        let mut maps = self.maps;
+        maps.push(other);
+        IntersectionZipped {
+            keys,
+            maps,
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for IntersectionZipped<'a, K, HashMap<K, V>>
+where
+    K: Hash + Eq,
+{
+    type IntoIter = IntersectionZippedIter<'a, K, HashMap<K, V>, IntoIter<K>>;
+    type Item = <IntersectionZippedIter<'a, K, HashMap<K, V>, IntoIter<K>> as Iterator>::Item;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntersectionZippedIter {
+            keys: self.keys.into_iter(),
+            maps: self.maps,
+        }
+    }
+}
+
+impl<'a, K, V> Iterator
+    for IntersectionZippedIter<'a, K, HashMap<K, V>, IntoIter<K>>
+where
+    K: Hash + Eq,
+{
+    type Item = (K, Vec<&'a V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.keys.next() {
+            None => None,
+            Some(k) => {
+                let mapped: Vec<&'a V> =
+                    self.maps.iter().map(|m| &m[&k]).collect();
                 Some((k, mapped))
             }
         }
     }
 }
 
+/// Merges two iterators of sorted keys into a sorted vector of distinct keys.
+fn merge_sorted_keys<'k, K, I1, I2>(lhs: I1, rhs: I2) -> Vec<&'k K>
+where
+    K: 'k + Ord,
+    I1: Iterator<Item = &'k K>,
+    I2: Iterator<Item = &'k K>, {
+    let mut lhs = lhs.peekable();
+    let mut rhs = rhs.peekable();
+    let mut keys = Vec::new();
+    loop {
+        let ordering = match (lhs.peek(), rhs.peek()) {
+            (None, None) => break,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(l), Some(r)) => l.cmp(r),
+        };
+        let key = match ordering {
+            Ordering::Less => lhs.next(),
+            Ordering::Greater => rhs.next(),
+            Ordering::Equal => {
+                rhs.next();
+                lhs.next()
+            }
+        };
+        keys.push(key.unwrap());
+    }
+    keys
+}
+
+/// Takes the sorted union of the two sets of keys for future iteration.
+/// Since the keys of a `BTreeMap` are already sorted, the union is obtained
+/// by merging the two sequences of keys.
+/// ```
+/// use math::iter::UnionZip;
+/// use std::collections::BTreeMap;
+/// let m1: BTreeMap<i32, i32> =
+///     vec![(1, 10), (3, 23), (4, 20)].into_iter().collect();
+/// let m2: BTreeMap<i32, i32> = vec![(0, 4), (1, 20), (4, 20), (9, 29)]
+///     .into_iter()
+///     .collect();
+///
+/// let mut iter = m1.union_zip(&m2).into_iter();
+/// assert_eq!(Some((0, vec![None, Some(&4)])), iter.next());
+/// assert_eq!(Some((1, vec![Some(&10), Some(&20)])), iter.next());
+/// assert_eq!(Some((3, vec![Some(&23), None])), iter.next());
+/// assert_eq!(Some((4, vec![Some(&20), Some(&20)])), iter.next());
+/// assert_eq!(Some((9, vec![None, Some(&29)])), iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+impl<K, V> UnionZip<K, BTreeMap<K, V>> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    fn union_zip<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> UnionZipped<'a, K, BTreeMap<K, V>> {
+        UnionZipped {
+            keys: merge_sorted_keys(self.keys(), other.keys()),
+            maps: vec![&self, other],
+        }
+    }
+}
+
+impl<'a, K, V> IntoUnionZip<'a, K, BTreeMap<K, V>>
+    for UnionZipped<'a, K, BTreeMap<K, V>>
+where
+    K: Ord,
+{
+    fn into_union_zip(
+        self,
+        other: &'a BTreeMap<K, V>,
+    ) -> UnionZipped<'a, K, BTreeMap<K, V>> {
+        let keys = merge_sorted_keys(self.keys.into_iter(), other.keys());
+        let mut maps = self.maps;
+        maps.push(other);
+        UnionZipped {
+            keys,
+            maps,
+        }
+    }
+}
+
+impl<'a, K, V> AsUnionZipped<'a, K, BTreeMap<K, V>> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    fn as_union_zipped(&'a self) -> UnionZipped<'a, K, BTreeMap<K, V>> {
+        UnionZipped {
+            keys: self.keys().collect(),
+            maps: vec![&self],
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for UnionZipped<'a, K, BTreeMap<K, V>>
+where
+    K: Ord + Clone,
+{
+    type IntoIter = UnionZippedIter<'a, K, BTreeMap<K, V>, IntoIter<&'a K>>;
+    type Item = (K, Vec<Option<&'a V>>);
+
+    fn into_iter(self) -> Self::IntoIter {
+        UnionZippedIter {
+            keys: self.keys.into_iter(),
+            maps: self.maps,
+        }
+    }
+}
+
+impl<'a, K, V> Iterator
+    for UnionZippedIter<'a, K, BTreeMap<K, V>, IntoIter<&'a K>>
+where
+    K: Ord + Clone,
+{
+    type Item = (K, Vec<Option<&'a V>>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.keys.next() {
+            None => None,
+            Some(k) => {
+                let mapped: Vec<Option<&'a V>> =
+                    self.maps.iter().map(|m| m.get(k)).collect();
+                Some((k.clone(), mapped))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -261,11 +574,244 @@ mod tests {
         assert_eq!(None, iter3.next());
     }
 
+    #[test]
+    fn test_union_zipped_keys_and_clone() {
+        let m1: HashMap<i32, i32> =
+            vec![(1, 10), (3, 23), (4, 20)].into_iter().collect();
+        let m2: HashMap<i32, i32> =
+            vec![(0, 4), (1, 20), (9, 29)].into_iter().collect();
+        let m3: HashMap<i32, i32> = vec![(2, 7)].into_iter().collect();
+
+        let zipped = m1.union_zip(&m2);
+        assert_eq!(zipped.keys(), &[&0, &1, &3, &4, &9]);
+        let first: Vec<(i32, Vec<Option<&i32>>)> =
+            zipped.clone().into_iter().collect();
+        let second: Vec<(i32, Vec<Option<&i32>>)> =
+            zipped.clone().into_iter().collect();
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 5);
+        assert_eq!(first[1], (1, vec![Some(&10), Some(&20)]));
+
+        let zipped = zipped.into_union_zip(&m3);
+        assert_eq!(zipped.keys(), &[&0, &1, &2, &3, &4, &9]);
+        assert_eq!(
+            zipped.clone().into_iter().nth(2),
+            Some((2, vec![None, None, Some(&7)]))
+        );
+        assert_eq!(zipped.into_iter().count(), 6);
+
+        let b1: BTreeMap<i32, i32> = m1.into_iter().collect();
+        let b2: BTreeMap<i32, i32> = m2.into_iter().collect();
+        let zipped = b1.union_zip(&b2);
+        assert_eq!(zipped.keys(), &[&0, &1, &3, &4, &9]);
+        assert_eq!(
+            zipped.clone().into_iter().collect::<Vec<_>>(),
+            zipped.into_iter().collect::<Vec<_>>()
+        );
+        assert!(BTreeMap::<i32, i32>::new()
+            .as_union_zipped()
+            .keys()
+            .is_empty());
+    }
+
     #[test]
     fn test_as_union_zipped() {
         let m: HashMap<i32, i32> =
             vec![(2, 10), (3, 0), (4, 1)].into_iter().collect();
         let union_zipped = m.as_union_zipped();
         assert_eq!(union_zipped.maps[0], &m);
+        assert_eq!(union_zipped.keys(), &[&2, &3, &4]);
+        assert_eq!(union_zipped.into_iter().collect::<Vec<_>>(), vec![
+            (2, vec![Some(&10)]),
+            (3, vec![Some(&0)]),
+            (4, vec![Some(&1)])
+        ]);
+    }
+
+    #[test]
+    fn test_as_union_zipped_chain_hashmap() {
+        let m1: HashMap<i32, i32> = vec![(1, 10), (3, 23), (4, 20), (12, 6)]
+            .into_iter()
+            .collect();
+        let m2: HashMap<i32, i32> = vec![(0, 4), (1, 20), (4, 20), (9, 29)]
+            .into_iter()
+            .collect();
+        let m3: HashMap<i32, i32> = vec![(0, 9), (3, 43), (4, 8), (14, 68)]
+            .into_iter()
+            .collect();
+
+        // starting from the by-reference entry point gives the same result as
+        // union_zip
+        assert_eq!(
+            m1.as_union_zipped()
+                .into_union_zip(&m2)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            m1.union_zip(&m2).into_iter().collect::<Vec<_>>()
+        );
+
+        let mut iter = m1
+            .as_union_zipped()
+            .into_union_zip(&m2)
+            .into_union_zip(&m3)
+            .into_iter();
+        assert_eq!(Some((0, vec![None, Some(&4), Some(&9)])), iter.next());
+        assert_eq!(Some((1, vec![Some(&10), Some(&20), None])), iter.next());
+        assert_eq!(Some((3, vec![Some(&23), None, Some(&43)])), iter.next());
+        assert_eq!(
+            Some((4, vec![Some(&20), Some(&20), Some(&8)])),
+            iter.next()
+        );
+        assert_eq!(Some((9, vec![None, Some(&29), None])), iter.next());
+        assert_eq!(Some((12, vec![Some(&6), None, None])), iter.next());
+        assert_eq!(Some((14, vec![None, None, Some(&68)])), iter.next());
+        assert_eq!(None, iter.next());
+
+        // the maps are only borrowed
+        assert_eq!(m1.len(), 4);
+    }
+
+    #[test]
+    fn test_as_union_zipped_borrows_keys() {
+        // the keys cannot be cloned, so they can only be borrowed
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        struct Key(i32);
+
+        let m1: HashMap<Key, i32> =
+            vec![(Key(3), 30), (Key(1), 10)].into_iter().collect();
+        let m2: HashMap<Key, i32> =
+            vec![(Key(2), 20), (Key(1), 11)].into_iter().collect();
+        let zipped = m1.as_union_zipped().into_union_zip(&m2);
+        assert_eq!(zipped.keys(), &[&Key(1), &Key(2), &Key(3)]);
+        let key_ptr = m1.get_key_value(&Key(3)).unwrap().0 as *const Key;
+        assert_eq!(zipped.keys()[2] as *const Key, key_ptr);
+
+        let b: BTreeMap<Key, i32> = m2.into_iter().collect();
+        assert_eq!(b.as_union_zipped().keys(), &[&Key(1), &Key(2)]);
+    }
+
+    #[test]
+    fn test_union_zip_iter_btreemap() {
+        let m1: BTreeMap<i32, i32> = vec![(1, 10), (3, 23), (4, 20), (12, 6)]
+            .into_iter()
+            .collect();
+        let m2: BTreeMap<i32, i32> = vec![(0, 4), (1, 20), (4, 20), (9, 29)]
+            .into_iter()
+            .collect();
+
+        let mut iter = m1.union_zip(&m2).into_iter();
+        assert_eq!(Some((0, vec![None, Some(&4)])), iter.next());
+        assert_eq!(Some((1, vec![Some(&10), Some(&20)])), iter.next());
+        assert_eq!(Some((3, vec![Some(&23), None])), iter.next());
+        assert_eq!(Some((4, vec![Some(&20), Some(&20)])), iter.next());
+        assert_eq!(Some((9, vec![None, Some(&29)])), iter.next());
+        assert_eq!(Some((12, vec![Some(&6), None])), iter.next());
+        assert_eq!(None, iter.next());
+
+        let m3: BTreeMap<i32, i32> = vec![(0, 9), (3, 43), (4, 8), (14, 68)]
+            .into_iter()
+            .collect();
+
+        let mut iter2 = m1.union_zip(&m2).into_union_zip(&m3).into_iter();
+        assert_eq!(Some((0, vec![None, Some(&4), Some(&9)])), iter2.next());
+        assert_eq!(Some((1, vec![Some(&10), Some(&20), None])), iter2.next());
+        assert_eq!(Some((3, vec![Some(&23), None, Some(&43)])), iter2.next());
+        assert_eq!(
+            Some((4, vec![Some(&20), Some(&20), Some(&8)])),
+            iter2.next()
+        );
+        assert_eq!(Some((9, vec![None, Some(&29), None])), iter2.next());
+        assert_eq!(Some((12, vec![Some(&6), None, None])), iter2.next());
+        assert_eq!(Some((14, vec![None, None, Some(&68)])), iter2.next());
+        assert_eq!(None, iter2.next());
+
+        let m4: BTreeMap<i32, i32> =
+            vec![(4, 73), (14, 64)].into_iter().collect();
+
+        let mut iter3 = m1
+            .union_zip(&m2)
+            .into_union_zip(&m3)
+            .into_union_zip(&m4)
+            .into_iter();
+        assert_eq!(
+            Some((0, vec![None, Some(&4), Some(&9), None])),
+            iter3.next()
+        );
+        assert_eq!(
+            Some((1, vec![Some(&10), Some(&20), None, None])),
+            iter3.next()
+        );
+        assert_eq!(
+            Some((3, vec![Some(&23), None, Some(&43), None])),
+            iter3.next()
+        );
+        assert_eq!(
+            Some((4, vec![Some(&20), Some(&20), Some(&8), Some(&73)])),
+            iter3.next()
+        );
+        assert_eq!(Some((9, vec![None, Some(&29), None, None])), iter3.next());
+        assert_eq!(Some((12, vec![Some(&6), None, None, None])), iter3.next());
+        assert_eq!(
+            Some((14, vec![None, None, Some(&68), Some(&64)])),
+            iter3.next()
+        );
+        assert_eq!(None, iter3.next());
+
+        let empty = BTreeMap::<i32, i32>::new();
+        assert_eq!(empty.union_zip(&empty).into_iter().next(), None);
+        assert_eq!(empty.union_zip(&m4).into_iter().collect::<Vec<_>>(), vec![
+            (4, vec![None, Some(&73)]),
+            (14, vec![None, Some(&64)])
+        ]);
+    }
+
+    #[test]
+    fn test_as_union_zipped_btreemap() {
+        let m: BTreeMap<i32, i32> =
+            vec![(2, 10), (3, 0), (4, 1)].into_iter().collect();
+        let union_zipped = m.as_union_zipped();
+        assert_eq!(union_zipped.maps[0], &m);
+        assert_eq!(union_zipped.keys, vec![&2, &3, &4]);
+    }
+
+    #[test]
+    fn test_intersection_zip_iter_hashmap() {
+        let m1: HashMap<i32, i32> =
+            vec![(1, 10), (3, 23), (4, 20), (9, 1), (12, 6)]
+                .into_iter()
+                .collect();
+        let m2: HashMap<i32, i32> =
+            vec![(0, 4), (1, 20), (4, 20), (9, 29), (12, 3)]
+                .into_iter()
+                .collect();
+        let m3: HashMap<i32, i32> =
+            vec![(0, 9), (1, 5), (3, 43), (9, 8), (12, 68)]
+                .into_iter()
+                .collect();
+
+        let mut iter = m1.intersection_zip(&m2).into_iter();
+        assert_eq!(Some((1, vec![&10, &20])), iter.next());
+        assert_eq!(Some((4, vec![&20, &20])), iter.next());
+        assert_eq!(Some((9, vec![&1, &29])), iter.next());
+        assert_eq!(Some((12, vec![&6, &3])), iter.next());
+        assert_eq!(None, iter.next());
+
+        let mut iter2 = m1
+            .intersection_zip(&m2)
+            .into_intersection_zip(&m3)
+            .into_iter();
+        assert_eq!(Some((1, vec![&10, &20, &5])), iter2.next());
+        assert_eq!(Some((9, vec![&1, &29, &8])), iter2.next());
+        assert_eq!(Some((12, vec![&6, &3, &68])), iter2.next());
+        assert_eq!(None, iter2.next());
+
+        let empty = HashMap::<i32, i32>::new();
+        assert_eq!(
+            m1.intersection_zip(&m2)
+                .into_intersection_zip(&empty)
+                .into_iter()
+                .next(),
+            None
+        );
     }
 }
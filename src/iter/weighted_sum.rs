@@ -55,9 +55,47 @@ where
     }
 }
 
+/// Same as `WeightedSum` except that the values only need to be numeric rather
+/// than floating point, so that the weighted sum of integer values is exact.
+///
+/// # Example
+/// ```
+/// use math::{interval::I64Interval, iter::weighted_sum::WeightedSumInt};
+///
+/// let arr = vec![
+///     (I64Interval::new(2, 5), 2i64),
+///     (I64Interval::new(10, 25), -3),
+/// ];
+/// // 4 * 2 + 16 * (-3)
+/// assert_eq!(arr.into_iter().weighted_sum_int(), -40);
+/// ```
+pub trait WeightedSumInt<Item, V>
+where
+    V: Copy + Num, {
+    fn weighted_sum_int(self) -> V;
+}
+
+impl<I, P, V> WeightedSumInt<(P, V), V> for I
+where
+    I: Iterator<Item = (P, V)>,
+    P: Finite,
+    V: Copy + Num + FromPrimitive,
+{
+    fn weighted_sum_int(self) -> V {
+        self.fold(V::zero(), |acc, (interval, value)| {
+            acc + V::from_usize(interval.size())
+                .expect("failed to convert from usize to V")
+                * value
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{interval::I64Interval, iter::weighted_sum::WeightedSum};
+    use crate::{
+        interval::I64Interval,
+        iter::weighted_sum::{WeightedSum, WeightedSumInt},
+    };
 
     #[test]
     fn test_weighted_sum() {
@@ -77,4 +115,37 @@ mod tests {
         // 10 * (-2) + 7 * 1.5 + 21 * 0.5
         assert_eq!(arr.into_iter().weighted_sum(), 1.0);
     }
+
+    #[test]
+    fn test_weighted_sum_int() {
+        let arr = vec![
+            (I64Interval::new(2, 5), 4i64),
+            (I64Interval::new(10, 25), 3),
+            (I64Interval::new(1, 5), 1),
+        ];
+        // 4 * 4 + 16 * 3 + 5 * 1
+        assert_eq!(arr.into_iter().weighted_sum_int(), 69);
+
+        let arr = vec![
+            (I64Interval::new(0, 9), -2i64),
+            (I64Interval::new(9, 15), 3),
+            (I64Interval::new(0, 20), 1),
+        ];
+        // 10 * (-2) + 7 * 3 + 21 * 1
+        assert_eq!(arr.into_iter().weighted_sum_int(), 22);
+
+        // exact beyond the precision of f64
+        let arr = vec![(I64Interval::new(1, 3), 3_000_000_000_000_001i64)];
+        assert_eq!(arr.into_iter().weighted_sum_int(), 9_000_000_000_000_003);
+
+        // empty intervals have zero weight
+        let arr = vec![(I64Interval::new(5, 4), 7i64)];
+        assert_eq!(arr.into_iter().weighted_sum_int(), 0);
+        assert_eq!(
+            Vec::<(I64Interval, i64)>::new()
+                .into_iter()
+                .weighted_sum_int(),
+            0
+        );
+    }
 }
@@ -1,6 +1,5 @@
 //! # Modeling mathematical constructs and their associated operations.
 
-#![feature(extract_if)]
 #![feature(type_ascription)]
 
 extern crate log;
@@ -4,11 +4,14 @@ use crate::{
     interval::{traits::Interval, I64Interval},
     set::{
         contiguous_integer_set::ContiguousIntegerSet,
-        ordered_integer_set::OrderedIntegerSet, traits::Intersect,
+        ordered_integer_set::OrderedIntegerSet,
+        traits::{Finite, Intersect, Set},
     },
     traits::SubsetIndexable,
 };
-use num::Num;
+use num::{FromPrimitive, Num};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{collections::BTreeMap, fmt::Debug};
 
 /// Maps `I64Interval`s to values of a numeric type `T`.
@@ -144,6 +147,107 @@ impl<T: Copy + Num> IntegerIntervalMap<T> {
         self.map.iter()
     }
 
+    /// Returns the stored entries whose intervals intersect the `query`, in
+    /// increasing order of the intervals. The intervals are returned as
+    /// stored, i.e. they are not clipped to the `query`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     interval::I64Interval,
+    ///     partition::integer_interval_map::IntegerIntervalMap,
+    /// };
+    ///
+    /// let mut interval_map = IntegerIntervalMap::new();
+    /// interval_map.aggregate(I64Interval::new(-1, 4), 2);
+    /// interval_map.aggregate(I64Interval::new(6, 8), 4);
+    /// interval_map.aggregate(I64Interval::new(4, 7), 1);
+    ///
+    /// assert_eq!(
+    ///     interval_map
+    ///         .entries_in(I64Interval::new(4, 7))
+    ///         .collect::<Vec<(I64Interval, i32)>>(),
+    ///     vec![
+    ///         (I64Interval::new(4, 4), 3),
+    ///         (I64Interval::new(5, 5), 1),
+    ///         (I64Interval::new(6, 7), 5),
+    ///     ]
+    /// );
+    /// ```
+    pub fn entries_in(
+        &self,
+        query: I64Interval,
+    ) -> impl Iterator<Item = (I64Interval, T)> + '_ {
+        let (start, end) = query.get_start_and_end();
+        let is_empty = query.is_empty();
+        let upper = if is_empty { start } else { end + 1 };
+        // Same as in `aggregate`, only the last interval starting before the
+        // query can intersect the query in addition to the intervals in the
+        // range.
+        let preceding = self
+            .map
+            .range(..ContiguousIntegerSet::new(start, start))
+            .next_back()
+            .filter(move |(interval, _)| {
+                !is_empty && interval.get_end() >= start
+            });
+        preceding
+            .into_iter()
+            .chain(self.map.range(
+                ContiguousIntegerSet::new(start, start)
+                    ..ContiguousIntegerSet::new(upper, upper),
+            ))
+            .map(|(&interval, &value)| (interval, value))
+    }
+
+    /// Multiplies every stored value by the `factor` in place, leaving the
+    /// intervals unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     interval::I64Interval,
+    ///     partition::integer_interval_map::IntegerIntervalMap,
+    /// };
+    ///
+    /// let mut interval_map = IntegerIntervalMap::new();
+    /// interval_map.aggregate(I64Interval::new(0, 5), 4.);
+    /// interval_map.scale_values(0.5);
+    /// assert_eq!(interval_map.get(&I64Interval::new(0, 5)), Some(2.));
+    /// ```
+    pub fn scale_values(&mut self, factor: T) {
+        for value in self.map.values_mut() {
+            *value = *value * factor;
+        }
+    }
+
+    /// Aggregates every `(interval, value)` entry of `other` into `self`, so
+    /// that the result is the same as if all the contributions to `other`
+    /// had been aggregated into `self` directly.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     interval::I64Interval,
+    ///     partition::integer_interval_map::IntegerIntervalMap,
+    /// };
+    ///
+    /// let mut m1 = IntegerIntervalMap::new();
+    /// m1.aggregate(I64Interval::new(0, 5), 1);
+    /// let mut m2 = IntegerIntervalMap::new();
+    /// m2.aggregate(I64Interval::new(3, 8), 2);
+    ///
+    /// m1.merge(&m2);
+    /// assert_eq!(m1.get(&I64Interval::new(0, 2)), Some(1));
+    /// assert_eq!(m1.get(&I64Interval::new(3, 5)), Some(3));
+    /// assert_eq!(m1.get(&I64Interval::new(6, 8)), Some(2));
+    /// ```
+    pub fn merge(&mut self, other: &IntegerIntervalMap<T>) {
+        for (&interval, &value) in other.iter() {
+            self.aggregate(interval, value);
+        }
+    }
+
     /// Returns the number of common refinements resulted from aggregating the
     /// intervals
     ///
@@ -181,6 +285,28 @@ impl<T: Copy + Num> IntegerIntervalMap<T> {
         self.map.len()
     }
 
+    /// Returns the total number of integer positions that have an associated
+    /// value.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     interval::I64Interval,
+    ///     partition::integer_interval_map::IntegerIntervalMap,
+    /// };
+    ///
+    /// let mut interval_map = IntegerIntervalMap::new();
+    /// interval_map.aggregate(I64Interval::new(-1, 4), 2);
+    /// interval_map.aggregate(I64Interval::new(6, 8), 4);
+    /// interval_map.aggregate(I64Interval::new(4, 7), 1);
+    ///
+    /// // the covered positions are [-1, 8]
+    /// assert_eq!(interval_map.covered_length(), 10);
+    /// ```
+    pub fn covered_length(&self) -> usize {
+        self.map.keys().map(|interval| interval.size()).sum()
+    }
+
     /// Converts into the underlying `BTreeMap`
     pub fn into_map(self) -> BTreeMap<I64Interval, T> {
         self.map
@@ -205,6 +331,60 @@ impl<T: Copy + Num> IntegerIntervalMap<T> {
     pub fn get(&self, key: &I64Interval) -> Option<T> {
         self.map.get(key).map(|&k| k)
     }
+
+    /// Returns the value associated with the interval containing the `point`,
+    /// or `None` if the `point` is not covered by any interval.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     interval::I64Interval,
+    ///     partition::integer_interval_map::IntegerIntervalMap,
+    /// };
+    ///
+    /// let mut interval_map = IntegerIntervalMap::new();
+    /// interval_map.aggregate(I64Interval::new(-1, 4), 2);
+    /// interval_map.aggregate(I64Interval::new(6, 8), 4);
+    /// interval_map.aggregate(I64Interval::new(4, 7), 1);
+    ///
+    /// assert_eq!(interval_map.value_at(-1), Some(2));
+    /// assert_eq!(interval_map.value_at(4), Some(3));
+    /// assert_eq!(interval_map.value_at(7), Some(5));
+    /// assert_eq!(interval_map.value_at(-2), None);
+    /// assert_eq!(interval_map.value_at(9), None);
+    /// ```
+    pub fn value_at(&self, point: i64) -> Option<T> {
+        self.get_set_containing(&I64Interval::new(point, point))
+            .and_then(|interval| self.get(&interval))
+    }
+}
+
+impl<T: Copy + Num + FromPrimitive> IntegerIntervalMap<T> {
+    /// Returns the sum over all the intervals of the associated value
+    /// multiplied by the size of the interval.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     interval::I64Interval,
+    ///     partition::integer_interval_map::IntegerIntervalMap,
+    /// };
+    ///
+    /// let mut interval_map = IntegerIntervalMap::new();
+    /// interval_map.aggregate(I64Interval::new(-1, 4), 2);
+    /// interval_map.aggregate(I64Interval::new(6, 8), 4);
+    /// interval_map.aggregate(I64Interval::new(4, 7), 1);
+    ///
+    /// // 5 * 2 + 1 * 3 + 1 * 1 + 2 * 5 + 1 * 4 = 28
+    /// assert_eq!(interval_map.integral(), 28);
+    /// ```
+    pub fn integral(&self) -> T {
+        self.map.iter().fold(T::zero(), |acc, (interval, &value)| {
+            acc + value
+                * T::from_usize(interval.size())
+                    .expect("failed to convert from usize to T")
+        })
+    }
 }
 
 impl<T: Copy + Num + Debug> Default for IntegerIntervalMap<T> {
@@ -213,6 +393,38 @@ impl<T: Copy + Num + Debug> Default for IntegerIntervalMap<T> {
     }
 }
 
+/// Serialized as the sequence of `(interval, value)` pairs in ascending order
+/// of the intervals. Maps with interval keys cannot be represented in formats
+/// such as JSON that only allow string keys.
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for IntegerIntervalMap<T> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.map.iter())
+    }
+}
+
+/// The deserialized pairs are added one by one through `aggregate`, so the
+/// resulting intervals are disjoint even if the input intervals overlap.
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for IntegerIntervalMap<T>
+where
+    T: Copy + Num + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let pairs = Vec::<(I64Interval, T)>::deserialize(deserializer)?;
+        let mut map = IntegerIntervalMap::new();
+        for (interval, value) in pairs {
+            map.aggregate(interval, value);
+        }
+        Ok(map)
+    }
+}
+
 impl<T> IntoIterator for IntegerIntervalMap<T> {
     type IntoIter = <BTreeMap<I64Interval, T> as IntoIterator>::IntoIter;
     type Item = <BTreeMap<I64Interval, T> as IntoIterator>::Item;
@@ -267,4 +479,201 @@ mod tests {
         ];
         assert_eq!(refined, expected);
     }
+
+    #[test]
+    fn test_merge() {
+        let contributions_1 = [
+            (I64Interval::new(-1, 4), 2),
+            (I64Interval::new(6, 8), 4),
+            (I64Interval::new(20, 25), -3),
+        ];
+        let contributions_2 = [
+            (I64Interval::new(4, 7), 1),
+            (I64Interval::new(0, 21), 5),
+            (I64Interval::new(30, 31), 6),
+        ];
+        let mut map1 = IntegerIntervalMap::new();
+        for &(interval, value) in contributions_1.iter() {
+            map1.aggregate(interval, value);
+        }
+        let mut map2 = IntegerIntervalMap::new();
+        for &(interval, value) in contributions_2.iter() {
+            map2.aggregate(interval, value);
+        }
+        let mut expected = IntegerIntervalMap::new();
+        for &(interval, value) in
+            contributions_1.iter().chain(contributions_2.iter())
+        {
+            expected.aggregate(interval, value);
+        }
+
+        let mut merged = map1.clone();
+        merged.merge(&map2);
+        assert_eq!(merged, expected);
+
+        let mut merged = map2.clone();
+        merged.merge(&map1);
+        assert_eq!(merged, expected);
+
+        let mut merged = IntegerIntervalMap::new();
+        merged.merge(&expected);
+        assert_eq!(merged, expected);
+    }
+
+    #[test]
+    fn test_covered_length_and_integral() {
+        let mut interval_map = IntegerIntervalMap::new();
+        assert_eq!(interval_map.covered_length(), 0);
+        assert_eq!(interval_map.integral(), 0);
+
+        interval_map.aggregate(I64Interval::new(-1, 4), 2);
+        interval_map.aggregate(I64Interval::new(6, 8), 4);
+        interval_map.aggregate(I64Interval::new(4, 7), 1);
+
+        //  -1 0 1 2 3 4 5 6 7 8
+        //   2 2 2 2 2 3 1 5 5 4
+        assert_eq!(interval_map.covered_length(), 10);
+        assert_eq!(interval_map.integral(), 2 * 5 + 3 + 1 + 5 * 2 + 4);
+
+        interval_map.aggregate(I64Interval::new(20, 22), -1);
+        assert_eq!(interval_map.covered_length(), 13);
+        assert_eq!(interval_map.integral(), 28 - 3);
+
+        let mut float_map = IntegerIntervalMap::new();
+        float_map.aggregate(I64Interval::new(0, 3), 0.5);
+        float_map.aggregate(I64Interval::new(2, 5), 1.5);
+        assert_eq!(float_map.covered_length(), 6);
+        assert_eq!(float_map.integral(), 0.5 * 2. + 2. * 2. + 1.5 * 2.);
+    }
+
+    #[test]
+    fn test_entries_in() {
+        let mut interval_map = IntegerIntervalMap::new();
+        interval_map.aggregate(I64Interval::new(-1, 4), 2);
+        interval_map.aggregate(I64Interval::new(6, 8), 4);
+        interval_map.aggregate(I64Interval::new(4, 7), 1);
+        interval_map.aggregate(I64Interval::new(12, 15), 6);
+        // the entries are now
+        // [-1, 3] -> 2, [4, 4] -> 3, [5, 5] -> 1, [6, 7] -> 5, [8, 8] -> 4,
+        // [12, 15] -> 6
+
+        let entries_in = |start, end| -> Vec<(I64Interval, i32)> {
+            interval_map
+                .entries_in(I64Interval::new(start, end))
+                .collect()
+        };
+        assert_eq!(entries_in(4, 7), vec![
+            (I64Interval::new(4, 4), 3),
+            (I64Interval::new(5, 5), 1),
+            (I64Interval::new(6, 7), 5),
+        ]);
+        // the preceding interval overlapping the start of the query
+        assert_eq!(entries_in(2, 4), vec![
+            (I64Interval::new(-1, 3), 2),
+            (I64Interval::new(4, 4), 3),
+        ]);
+        assert_eq!(entries_in(7, 13), vec![
+            (I64Interval::new(6, 7), 5),
+            (I64Interval::new(8, 8), 4),
+            (I64Interval::new(12, 15), 6),
+        ]);
+        assert_eq!(entries_in(13, 14), vec![(I64Interval::new(12, 15), 6)]);
+        assert_eq!(
+            entries_in(-10, 100),
+            interval_map
+                .iter()
+                .map(|(&i, &v)| (i, v))
+                .collect::<Vec<(I64Interval, i32)>>()
+        );
+
+        // gaps and empty queries
+        assert_eq!(entries_in(9, 11), vec![]);
+        assert_eq!(entries_in(16, 20), vec![]);
+        assert_eq!(entries_in(-5, -2), vec![]);
+        assert_eq!(entries_in(5, 4), vec![]);
+        assert_eq!(entries_in(14, 2), vec![]);
+    }
+
+    #[test]
+    fn test_scale_values() {
+        let mut interval_map = IntegerIntervalMap::new();
+        interval_map.aggregate(I64Interval::new(-1, 4), 2);
+        interval_map.aggregate(I64Interval::new(6, 8), 4);
+        interval_map.aggregate(I64Interval::new(4, 7), 1);
+        let intervals: Vec<I64Interval> =
+            interval_map.iter().map(|(&i, _)| i).collect();
+
+        interval_map.scale_values(3);
+        assert_eq!(
+            interval_map
+                .iter()
+                .map(|(&i, &v)| (i, v))
+                .collect::<Vec<(I64Interval, i32)>>(),
+            vec![
+                (I64Interval::new(-1, 3), 6),
+                (I64Interval::new(4, 4), 9),
+                (I64Interval::new(5, 5), 3),
+                (I64Interval::new(6, 7), 15),
+                (I64Interval::new(8, 8), 12),
+            ]
+        );
+        assert_eq!(
+            interval_map
+                .iter()
+                .map(|(&i, _)| i)
+                .collect::<Vec<I64Interval>>(),
+            intervals
+        );
+
+        interval_map.scale_values(0);
+        assert_eq!(interval_map.len(), intervals.len());
+        assert!(interval_map.iter().all(|(_, &v)| v == 0));
+
+        let mut empty = IntegerIntervalMap::<f64>::new();
+        empty.scale_values(2.);
+        assert_eq!(empty.len(), 0);
+    }
+
+    #[test]
+    fn test_value_at() {
+        let mut interval_map = IntegerIntervalMap::new();
+        interval_map.aggregate(I64Interval::new(-1, 4), 2);
+        interval_map.aggregate(I64Interval::new(6, 8), 4);
+        interval_map.aggregate(I64Interval::new(4, 7), 1);
+
+        //  -1 0 1 2 3 4 5 6 7 8
+        //   2 2 2 2 2 3 1 5 5 4
+        let expected = [2, 2, 2, 2, 2, 3, 1, 5, 5, 4];
+        for (point, value) in (-1..=8).zip(expected) {
+            assert_eq!(interval_map.value_at(point), Some(value));
+        }
+        assert_eq!(interval_map.value_at(-2), None);
+        assert_eq!(interval_map.value_at(9), None);
+        assert_eq!(IntegerIntervalMap::<i32>::new().value_at(0), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut map = IntegerIntervalMap::new();
+        map.aggregate(I64Interval::new(1, 5), 1);
+        map.aggregate(I64Interval::new(3, 6), 2);
+        map.aggregate(I64Interval::new(10, 12), 4);
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(
+            serde_json::from_str::<IntegerIntervalMap<i32>>(&json).unwrap(),
+            map
+        );
+
+        // overlapping intervals are aggregated
+        let json = r#"[
+            [{"start":1,"end":5},1],
+            [{"start":10,"end":12},4],
+            [{"start":3,"end":6},2]
+        ]"#;
+        assert_eq!(
+            serde_json::from_str::<IntegerIntervalMap<i32>>(json).unwrap(),
+            map
+        );
+    }
 }
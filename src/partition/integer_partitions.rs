@@ -3,15 +3,16 @@
 use crate::set::{
     contiguous_integer_set::ContiguousIntegerSet,
     ordered_integer_set::OrderedIntegerSet,
+    traits::{Finite, Intersect, Set},
 };
-use num::{Integer, ToPrimitive};
+use num::{FromPrimitive, Integer, ToPrimitive};
 use rayon::iter::{
     plumbing::{
         bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer,
     },
     IndexedParallelIterator, IntoParallelIterator, ParallelIterator,
 };
-use std::ops::Index;
+use std::{fmt, iter::Sum, ops::Index};
 
 pub type Partition<T> = OrderedIntegerSet<T>;
 
@@ -28,11 +29,65 @@ impl<T: Copy + Integer + ToPrimitive> IntegerPartitions<T> {
         }
     }
 
+    /// Same as `new` except that an error is returned if any two of the
+    /// partitions are not disjoint. The error identifies the first
+    /// overlapping pair `(i, j)` with `i < j` in lexicographic order.
+    ///
+    /// # Example
+    /// ```
+    /// use math::partition::integer_partitions::{IntegerPartitions, Partition};
+    ///
+    /// assert!(IntegerPartitions::try_new(vec![
+    ///     Partition::from_slice(&[[1, 3], [8, 9]]),
+    ///     Partition::from_slice(&[[4, 5]]),
+    /// ])
+    /// .is_ok());
+    /// assert!(IntegerPartitions::try_new(vec![
+    ///     Partition::from_slice(&[[1, 3], [8, 9]]),
+    ///     Partition::from_slice(&[[4, 8]]),
+    /// ])
+    /// .is_err());
+    /// ```
+    pub fn try_new(
+        partitions: Vec<Partition<T>>,
+    ) -> Result<IntegerPartitions<T>, String> {
+        for (i, p) in partitions.iter().enumerate() {
+            for (j, q) in partitions.iter().enumerate().skip(i + 1) {
+                if p.has_non_empty_intersection_with(q) {
+                    return Err(format!(
+                        "partitions {} and {} are not disjoint",
+                        i, j
+                    ));
+                }
+            }
+        }
+        Ok(IntegerPartitions::new(partitions))
+    }
+
     #[inline]
     pub fn num_partitions(&self) -> usize {
         self.partitions.len()
     }
 
+    /// Returns the index of the partition containing the `element`, or `None`
+    /// if none of the partitions contains it.
+    ///
+    /// # Example
+    /// ```
+    /// use math::partition::integer_partitions::{IntegerPartitions, Partition};
+    ///
+    /// let partitions = IntegerPartitions::new(vec![
+    ///     Partition::from_slice(&[[1, 3], [8, 9]]),
+    ///     Partition::from_slice(&[[4, 5]]),
+    /// ]);
+    /// assert_eq!(partitions.partition_containing(8), Some(0));
+    /// assert_eq!(partitions.partition_containing(5), Some(1));
+    /// assert_eq!(partitions.partition_containing(6), None);
+    /// ```
+    pub fn partition_containing(&self, element: T) -> Option<usize> {
+        self.partitions.iter().position(|p| p.contains(&element))
+    }
+
     /// Creates an iterator that iterates through the partitions.
     pub fn iter(&self) -> IntegerPartitionIter<T> {
         IntegerPartitionIter {
@@ -54,6 +109,54 @@ impl<T: Copy + Integer + ToPrimitive> IntegerPartitions<T> {
     }
 }
 
+impl<T> IntegerPartitions<T>
+where
+    T: Copy + Integer + FromPrimitive + ToPrimitive + Sum + fmt::Debug,
+{
+    /// Redistributes the union of all the elements into `k` partitions of
+    /// consecutive elements, where the sizes of any two partitions differ by
+    /// at most one. The first `total % k` partitions get the extra element,
+    /// where `total` is the number of elements in the union. Some of the
+    /// partitions will be empty if `k > total`.
+    ///
+    /// # Panics
+    /// Panics if `k` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use math::partition::integer_partitions::{IntegerPartitions, Partition};
+    ///
+    /// let partitions = IntegerPartitions::new(vec![
+    ///     Partition::from_slice(&[[1, 3], [8, 9]]),
+    ///     Partition::from_slice(&[[4, 5]]),
+    /// ]);
+    /// assert_eq!(
+    ///     partitions.into_balanced(3),
+    ///     IntegerPartitions::new(vec![
+    ///         Partition::from_slice(&[[1, 3]]),
+    ///         Partition::from_slice(&[[4, 5]]),
+    ///         Partition::from_slice(&[[8, 9]]),
+    ///     ])
+    /// );
+    /// ```
+    pub fn into_balanced(self, k: usize) -> IntegerPartitions<T> {
+        assert!(k > 0, "the number of partitions must be positive");
+        let union = self.union();
+        let total = union.size();
+        let (quotient, remainder) = (total / k, total % k);
+        let mut start = 0;
+        let partitions = (0..k)
+            .map(|i| {
+                let size = quotient + if i < remainder { 1 } else { 0 };
+                let partition = union.slice(start..start + size);
+                start += size;
+                partition
+            })
+            .collect();
+        IntegerPartitions::new(partitions)
+    }
+}
+
 impl<T: Copy + Integer + ToPrimitive> Index<usize> for IntegerPartitions<T> {
     type Output = Partition<T>;
 
@@ -226,6 +329,7 @@ mod tests {
     use crate::{
         partition::integer_partitions::{IntegerPartitions, Partition},
         set::{ordered_integer_set::OrderedIntegerSet, traits::Finite},
+        traits::ToIterator,
     };
     use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
@@ -272,6 +376,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_partition_containing() {
+        let partitions = IntegerPartitions::<i32>::new(vec![
+            Partition::from_slice(&[[1, 3], [8, 9]]),
+            Partition::from_slice(&[[4, 5], [10, 14]]),
+            Partition::from_slice(&[[21, 24]]),
+        ]);
+        let expected = |i| match i {
+            1..=3 | 8..=9 => Some(0),
+            4..=5 | 10..=14 => Some(1),
+            21..=24 => Some(2),
+            _ => None,
+        };
+        for i in -2..=26 {
+            assert_eq!(partitions.partition_containing(i), expected(i));
+        }
+        assert_eq!(
+            IntegerPartitions::<usize>::new(vec![]).partition_containing(0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_try_new() {
+        let partition_list = vec![
+            Partition::from_slice(&[[1, 3], [8, 9]]),
+            Partition::from_slice(&[[4, 5], [10, 14]]),
+            Partition::from_slice(&[[21, 24]]),
+        ];
+        assert_eq!(
+            IntegerPartitions::try_new(partition_list.clone()),
+            Ok(IntegerPartitions::new(partition_list))
+        );
+        assert_eq!(
+            IntegerPartitions::<usize>::try_new(vec![]),
+            Ok(IntegerPartitions::new(vec![]))
+        );
+
+        assert_eq!(
+            IntegerPartitions::try_new(vec![
+                Partition::from_slice(&[[1, 3], [8, 9]]),
+                Partition::from_slice(&[[4, 5], [10, 14]]),
+                Partition::from_slice(&[[14, 24]]),
+                Partition::from_slice(&[[0, 1]]),
+            ]),
+            Err("partitions 0 and 3 are not disjoint".to_string())
+        );
+        assert_eq!(
+            IntegerPartitions::try_new(vec![
+                Partition::from_slice(&[[1, 3]]),
+                Partition::from_slice(&[[4, 5], [10, 14]]),
+                Partition::from_slice(&[[14, 24]]),
+            ]),
+            Err("partitions 1 and 2 are not disjoint".to_string())
+        );
+    }
+
+    #[test]
+    fn test_into_balanced() {
+        fn test(partitions: IntegerPartitions<i32>, k: usize) {
+            let union = partitions.union();
+            let total = union.size();
+            let balanced = partitions.into_balanced(k);
+            assert_eq!(balanced.num_partitions(), k);
+            assert_eq!(balanced.union(), union);
+
+            let sizes: Vec<usize> = balanced.iter().map(|p| p.size()).collect();
+            assert_eq!(sizes.iter().sum::<usize>(), total);
+            let min_size = *sizes.iter().min().unwrap();
+            let max_size = *sizes.iter().max().unwrap();
+            assert!(max_size - min_size <= 1);
+            assert_eq!(min_size, total / k);
+
+            // the order of the elements is preserved
+            let elements: Vec<i32> = balanced
+                .iter()
+                .flat_map(|p| p.to_iter().collect::<Vec<i32>>())
+                .collect();
+            assert_eq!(elements, union.to_iter().collect::<Vec<i32>>());
+        }
+        let partitions = IntegerPartitions::new(vec![
+            Partition::from_slice(&[[1, 3], [8, 9]]),
+            Partition::from_slice(&[[4, 5], [10, 14]]),
+            Partition::from_slice(&[[21, 24]]),
+        ]);
+        for k in 1..=20 {
+            test(partitions.clone(), k);
+        }
+        test(IntegerPartitions::new(vec![]), 3);
+
+        assert_eq!(
+            partitions.into_balanced(4),
+            IntegerPartitions::new(vec![
+                Partition::from_slice(&[[1, 4]]),
+                Partition::from_slice(&[[5, 5], [8, 10]]),
+                Partition::from_slice(&[[11, 14]]),
+                Partition::from_slice(&[[21, 24]]),
+            ])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of partitions must be positive")]
+    fn test_into_balanced_zero_partitions() {
+        IntegerPartitions::new(vec![Partition::from_slice(&[[1, 3]])])
+            .into_balanced(0);
+    }
+
     #[test]
     fn test_partitions_iter() {
         macro_rules! test_with_type {
@@ -2,11 +2,12 @@
 
 use crate::{
     interval::traits::Interval,
+    partition::integer_partitions::{IntegerPartitions, Partition},
     search::binary_search::BinarySearch,
     set::{
         contiguous_integer_set::ContiguousIntegerSet,
         ordered_integer_set::OrderedIntegerSet,
-        traits::{Intersect, Refineable},
+        traits::{Finite, Intersect, Refineable, Set},
     },
     traits::SubsetIndexable,
 };
@@ -96,6 +97,47 @@ impl<E: Integer + Copy> OrderedIntervalPartitions<E> {
 }
 
 impl<E: Integer + Copy + ToPrimitive> OrderedIntervalPartitions<E> {
+    /// Returns the total number of integers covered by the partitions.
+    ///
+    /// # Example
+    /// ```
+    /// use math::partition::ordered_interval_partitions::OrderedIntervalPartitions;
+    ///
+    /// let p = OrderedIntervalPartitions::from_slice(&[[-1, 4], [8, 10]]);
+    /// assert_eq!(p.total_size(), 9);
+    /// ```
+    pub fn total_size(&self) -> usize {
+        self.partitions.iter().map(|p| p.size()).sum()
+    }
+
+    /// Returns whether the non-empty partitions exactly tile a single
+    /// contiguous range, i.e. each partition starts right after the end of the
+    /// previous one. Returns `true` if there are no non-empty partitions.
+    ///
+    /// # Example
+    /// ```
+    /// use math::partition::ordered_interval_partitions::OrderedIntervalPartitions;
+    ///
+    /// assert!(OrderedIntervalPartitions::from_slice(&[[-1, 4], [5, 10]])
+    ///     .is_contiguous());
+    /// assert!(!OrderedIntervalPartitions::from_slice(&[[-1, 4], [6, 10]])
+    ///     .is_contiguous());
+    /// ```
+    pub fn is_contiguous(&self) -> bool {
+        let mut non_empty = self.partitions.iter().filter(|p| !p.is_empty());
+        let mut end = match non_empty.next() {
+            None => return true,
+            Some(p) => p.get_end(),
+        };
+        for p in non_empty {
+            if p.get_start() != end + E::one() {
+                return false;
+            }
+            end = p.get_end();
+        }
+        true
+    }
+
     pub fn to_ordered_integer_set(&self) -> OrderedIntegerSet<E> {
         OrderedIntegerSet::from_contiguous_integer_sets(self.partitions.clone())
     }
@@ -103,6 +145,30 @@ impl<E: Integer + Copy + ToPrimitive> OrderedIntervalPartitions<E> {
     pub fn into_ordered_integer_set(self) -> OrderedIntegerSet<E> {
         OrderedIntegerSet::from_contiguous_integer_sets(self.into_vec())
     }
+
+    /// Converts each interval into its own single-interval `Partition`,
+    /// preserving the order and the number of partitions.
+    ///
+    /// # Example
+    /// ```
+    /// use math::partition::{
+    ///     integer_partitions::Partition,
+    ///     ordered_interval_partitions::OrderedIntervalPartitions,
+    /// };
+    ///
+    /// let p = OrderedIntervalPartitions::from_slice(&[[-1, 4], [8, 10]]);
+    /// let integer_partitions = p.to_integer_partitions();
+    /// assert_eq!(integer_partitions.num_partitions(), 2);
+    /// assert_eq!(integer_partitions[1], Partition::from_slice(&[[8, 10]]));
+    /// ```
+    pub fn to_integer_partitions(&self) -> IntegerPartitions<E> {
+        IntegerPartitions::new(
+            self.partitions
+                .iter()
+                .map(|&p| Partition::from_contiguous_integer_sets(vec![p]))
+                .collect(),
+        )
+    }
 }
 
 impl<E: Integer + Copy + Hash>
@@ -250,14 +316,54 @@ impl<E: Integer + Copy> Refineable<OrderedIntervalPartitions<E>>
     }
 }
 
+/// The intervals of each set are treated as the partitions, so the common
+/// refinement is that of the two sets' interval boundaries.
+impl<E: Integer + Copy + ToPrimitive> Refineable<OrderedIntervalPartitions<E>>
+    for OrderedIntegerSet<E>
+{
+    /// # Example
+    /// ```
+    /// use math::set::{
+    ///     contiguous_integer_set::ContiguousIntegerSet,
+    ///     ordered_integer_set::OrderedIntegerSet, traits::Refineable,
+    /// };
+    /// let s1 = OrderedIntegerSet::from_slice(&[[-1, 4], [8, 10]]);
+    /// let s2 = OrderedIntegerSet::from_slice(&[[3, 7]]);
+    /// assert_eq!(
+    ///     s1.get_common_refinement(&s2).into_vec(),
+    ///     [[-1, 2], [3, 4], [5, 7], [8, 10]]
+    ///         .iter()
+    ///         .map(|[a, b]| ContiguousIntegerSet::new(*a, *b))
+    ///         .collect::<Vec<ContiguousIntegerSet<i32>>>()
+    /// );
+    /// ```
+    fn get_common_refinement(
+        &self,
+        other: &OrderedIntegerSet<E>,
+    ) -> OrderedIntervalPartitions<E> {
+        let lhs = OrderedIntervalPartitions::from_vec_with_trusted_order(
+            self.to_non_empty_intervals().into_intervals(),
+        );
+        let rhs = OrderedIntervalPartitions::from_vec_with_trusted_order(
+            other.to_non_empty_intervals().into_intervals(),
+        );
+        lhs.get_common_refinement(&rhs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use num::Integer;
+    use num::{Integer, ToPrimitive};
 
     use crate::{
-        partition::ordered_interval_partitions::OrderedIntervalPartitions,
+        partition::{
+            integer_partitions::Partition,
+            ordered_interval_partitions::OrderedIntervalPartitions,
+        },
         set::{
-            contiguous_integer_set::ContiguousIntegerSet, traits::Refineable,
+            contiguous_integer_set::ContiguousIntegerSet,
+            ordered_integer_set::OrderedIntegerSet,
+            traits::{Finite, Refineable},
         },
     };
 
@@ -339,6 +445,116 @@ mod tests {
         test(&[[0usize, 4], [5, 7]], &[], &[[0, 4], [5, 7]]);
     }
 
+    #[test]
+    fn test_ordered_integer_set_common_refinement() {
+        fn test<E: Integer + Copy + ToPrimitive + std::fmt::Debug>(
+            a: &[[E; 2]],
+            b: &[[E; 2]],
+            expected: &[[E; 2]],
+        ) {
+            let s1 = OrderedIntegerSet::from_slice(a);
+            let s2 = OrderedIntegerSet::from_slice(b);
+            let expected = expected
+                .iter()
+                .map(|[a, b]| ContiguousIntegerSet::new(*a, *b))
+                .collect::<Vec<ContiguousIntegerSet<E>>>();
+            assert_eq!(s1.get_common_refinement(&s2).into_vec(), expected);
+            assert_eq!(s2.get_common_refinement(&s1).into_vec(), expected);
+        }
+        test::<usize>(&[], &[], &[]);
+        test(&[[0usize, 4], [6, 10]], &[[1, 2], [4, 6]], &[
+            [0, 0],
+            [1, 2],
+            [3, 3],
+            [4, 4],
+            [5, 5],
+            [6, 6],
+            [7, 10],
+        ]);
+        test(&[[0usize, 5], [8, 10]], &[[6, 7], [11, 13]], &[
+            [0, 5],
+            [6, 7],
+            [8, 10],
+            [11, 13],
+        ]);
+        test(&[[-1, 5]], &[[-2, 0]], &[[-2, -2], [-1, 0], [1, 5]]);
+        test(&[[1, 100]], &[[3, 5], [23, 30]], &[
+            [1, 2],
+            [3, 5],
+            [6, 22],
+            [23, 30],
+            [31, 100],
+        ]);
+        test(&[[2usize, 6], [8, 12]], &[[0, 3], [5, 13]], &[
+            [0, 1],
+            [2, 3],
+            [4, 4],
+            [5, 6],
+            [7, 7],
+            [8, 12],
+            [13, 13],
+        ]);
+        test(&[[0usize, 4], [7, 9]], &[], &[[0, 4], [7, 9]]);
+
+        // the sets coalesce overlapping and adjacent intervals on creation
+        test(&[[0usize, 3], [4, 8]], &[[2, 4]], &[[0, 1], [2, 4], [5, 8]]);
+        test(&[[0, 5], [3, 8]], &[[10, 12]], &[[0, 8], [10, 12]]);
+
+        // empty intervals are ignored
+        test(&[[5, 4], [0, 2]], &[[2, 3], [9, 8]], &[[0, 1], [2, 2], [
+            3, 3,
+        ]]);
+    }
+
+    #[test]
+    fn test_total_size_and_is_contiguous() {
+        fn test(
+            partitions: &[[i32; 2]],
+            total_size: usize,
+            is_contiguous: bool,
+        ) {
+            let p = OrderedIntervalPartitions::from_slice(partitions);
+            assert_eq!(p.total_size(), total_size);
+            assert_eq!(p.is_contiguous(), is_contiguous);
+        }
+        test(&[], 0, true);
+        test(&[[3, 3]], 1, true);
+        test(&[[-2, 5]], 8, true);
+        test(&[[0, 4], [5, 7]], 8, true);
+        test(&[[5, 7], [0, 4], [8, 8]], 9, true);
+        test(&[[0, 4], [6, 7]], 7, false);
+        test(&[[0, 4], [5, 7], [10, 12]], 11, false);
+        test(&[[-3, -1], [1, 2]], 5, false);
+
+        // empty partitions are ignored
+        test(&[[0, 4], [9, 8], [5, 7]], 8, true);
+        test(&[[2, 1]], 0, true);
+    }
+
+    #[test]
+    fn test_to_integer_partitions() {
+        let p = OrderedIntervalPartitions::from_slice(&[
+            [12, 15],
+            [0, 4],
+            [5, 7],
+            [20, 20],
+        ]);
+        let integer_partitions = p.to_integer_partitions();
+        assert_eq!(integer_partitions.num_partitions(), p.num_partitions());
+        for (i, interval) in p.get_partitions_by_ref().iter().enumerate() {
+            let (start, end) = interval.get_start_and_end();
+            assert_eq!(
+                integer_partitions[i],
+                Partition::from_slice(&[[start, end]])
+            );
+        }
+        assert_eq!(integer_partitions.union(), p.to_ordered_integer_set());
+        assert_eq!(integer_partitions.union().size(), p.total_size());
+
+        let empty = OrderedIntervalPartitions::<i32>::from_slice(&[]);
+        assert_eq!(empty.to_integer_partitions().num_partitions(), 0);
+    }
+
     #[test]
     fn test_num_overlapped_partitions_by() {
         fn test<E: Integer + Copy + std::fmt::Debug>(
@@ -1,6 +1,13 @@
 //! # Blanket implementations for online sampling algorithms
 
-use rand::distributions::{Distribution, Uniform};
+use rand::{
+    distributions::{Distribution, Uniform},
+    Rng,
+};
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
 
 use crate::{
     set::traits::Finite,
@@ -17,6 +24,20 @@ pub trait Sample<'a, I: Iterator<Item = E>, E, O: Collecting<E> + Default>:
     fn sample_subset_without_replacement<'s: 'a>(
         &'s self,
         size: usize,
+    ) -> Result<O, String> {
+        self.sample_subset_without_replacement_with_rng(
+            size,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// same as `sample_subset_without_replacement` except that the randomness
+    /// is drawn from the provided `rng`, e.g. a seeded `StdRng` for
+    /// reproducible samples
+    fn sample_subset_without_replacement_with_rng<'s: 'a, R: Rng>(
+        &'s self,
+        size: usize,
+        rng: &mut R,
     ) -> Result<O, String> {
         let mut remaining = self.size();
         if size > remaining {
@@ -27,11 +48,10 @@ pub trait Sample<'a, I: Iterator<Item = E>, E, O: Collecting<E> + Default>:
         }
         let mut samples = O::default();
         let mut needed = size;
-        let mut rng = rand::thread_rng();
         let uniform = Uniform::new(0., 1.);
 
         for element in self.to_iter() {
-            if uniform.sample(&mut rng) <= (needed as f64 / remaining as f64) {
+            if uniform.sample(rng) <= (needed as f64 / remaining as f64) {
                 samples.collect(element);
                 needed -= 1;
             }
@@ -43,33 +63,153 @@ pub trait Sample<'a, I: Iterator<Item = E>, E, O: Collecting<E> + Default>:
     fn sample_with_replacement<'s: 'a>(
         &'s self,
         size: usize,
+    ) -> Result<O, String> {
+        self.sample_with_replacement_with_rng(size, &mut rand::thread_rng())
+    }
+
+    /// same as `sample_with_replacement` except that the randomness is drawn
+    /// from the provided `rng`
+    ///
+    /// The default implementation walks the iterator up to each drawn index,
+    /// which takes `O(n)` time per sample for a population of `n` elements.
+    /// Implementors supporting indexed access should override it, see
+    /// `sample_with_replacement_by_index`.
+    fn sample_with_replacement_with_rng<'s: 'a, R: Rng>(
+        &'s self,
+        size: usize,
+        rng: &mut R,
+    ) -> Result<O, String> {
+        sample_with_replacement_by_index(self.size(), size, rng, |i| {
+            self.to_iter().nth(i).unwrap()
+        })
+    }
+
+    /// samples `size` elements without replacement, where the probability of
+    /// drawing each element is proportional to its weight given by the
+    /// `weight_fn`. The samples are drawn in a single pass over the population
+    /// using the A-Res reservoir algorithm by Efraimidis and Spirakis, and are
+    /// collected in the order in which they appear in the population.
+    ///
+    /// returns Err if `size` is larger than the population size, or if any of
+    /// the weights is negative or NaN.
+    fn sample_weighted<'s: 'a, R: Rng, F: Fn(&E) -> f64>(
+        &'s self,
+        size: usize,
+        weight_fn: F,
+        rng: &mut R,
     ) -> Result<O, String> {
         let population_size = self.size();
-        if population_size == 0 {
-            return Err(
-                "cannot sample from a population of 0 elements".to_string()
-            );
+        if size > population_size {
+            return Err(format!(
+                "desired sample size {} > population size {}",
+                size, population_size
+            ));
         }
+        // a min-heap of the elements with the largest keys seen so far
+        let mut reservoir: BinaryHeap<Reverse<WeightedKey<E>>> =
+            BinaryHeap::with_capacity(size + 1);
+        for (index, element) in self.to_iter().enumerate() {
+            let weight = weight_fn(&element);
+            if weight.is_nan() || weight < 0. {
+                return Err(format!(
+                    "weight {} of the element at index {} is not \
+                     non-negative",
+                    weight, index
+                ));
+            }
+            // the key u^(1 / w) is compared in the log space for numerical
+            // stability, where u is uniformly distributed in (0, 1]
+            let key = if weight == 0. {
+                f64::NEG_INFINITY
+            } else {
+                (1. - rng.gen::<f64>()).ln() / weight
+            };
+            reservoir.push(Reverse(WeightedKey {
+                key,
+                index,
+                element,
+            }));
+            if reservoir.len() > size {
+                reservoir.pop();
+            }
+        }
+        let mut selected: Vec<WeightedKey<E>> =
+            reservoir.into_iter().map(|Reverse(k)| k).collect();
+        selected.sort_by_key(|k| k.index);
+
         let mut samples = O::default();
-        let mut rng = rand::thread_rng();
-        let uniform = Uniform::new(0., population_size as f64);
-        for _ in 0..size {
-            samples.collect(
-                self.to_iter()
-                    .nth(uniform.sample(&mut rng) as usize)
-                    .unwrap(),
-            );
+        for k in selected.into_iter() {
+            samples.collect(k.element);
         }
         Ok(samples)
     }
 }
 
+/// Draws `size` indices uniformly from `[0, population_size)` with replacement
+/// and collects the elements at those indices given by `element_at`.
+///
+/// returns Err if the `population_size` is 0
+pub(crate) fn sample_with_replacement_by_index<E, O, R, F>(
+    population_size: usize,
+    size: usize,
+    rng: &mut R,
+    element_at: F,
+) -> Result<O, String>
+where
+    O: Collecting<E> + Default,
+    R: Rng,
+    F: Fn(usize) -> E, {
+    if population_size == 0 {
+        return Err("cannot sample from a population of 0 elements".to_string());
+    }
+    let mut samples = O::default();
+    let uniform = Uniform::new(0, population_size);
+    for _ in 0..size {
+        samples.collect(element_at(uniform.sample(rng)));
+    }
+    Ok(samples)
+}
+
+/// An element keyed by its random key in weighted sampling, where ties are
+/// broken by the `index` of the element in the population.
+struct WeightedKey<E> {
+    key: f64,
+    index: usize,
+    element: E,
+}
+
+impl<E> PartialEq for WeightedKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<E> Eq for WeightedKey<E> {}
+
+impl<E> PartialOrd for WeightedKey<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E> Ord for WeightedKey<E> {
+    /// the keys are never NaN
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key
+            .partial_cmp(&other.key)
+            .unwrap()
+            .then_with(|| self.index.cmp(&other.index))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::set::{
         contiguous_integer_set::ContiguousIntegerSet,
-        ordered_integer_set::OrderedIntegerSet, traits::Finite,
+        ordered_integer_set::OrderedIntegerSet,
+        traits::{Finite, Intersect},
     };
+    use rand::{rngs::StdRng, SeedableRng};
 
     use super::Sample;
 
@@ -100,4 +240,132 @@ mod tests {
             .sample_with_replacement(num_samples)
             .is_err());
     }
+
+    #[test]
+    fn test_sampling_with_seeded_rng() {
+        let set =
+            OrderedIntegerSet::from_slice(&[[-89, -23], [-2, 100], [300, 345]]);
+        let mut rng_1 = StdRng::seed_from_u64(7);
+        let mut rng_2 = StdRng::seed_from_u64(7);
+        for _ in 0..5 {
+            let samples_1 = set
+                .sample_subset_without_replacement_with_rng(18, &mut rng_1)
+                .unwrap();
+            let samples_2 = set
+                .sample_subset_without_replacement_with_rng(18, &mut rng_2)
+                .unwrap();
+            assert_eq!(samples_1.size(), 18);
+            assert_eq!(samples_1, samples_2);
+        }
+
+        let v: Vec<i32> = (0..1000).collect();
+        let mut rng_1 = StdRng::seed_from_u64(11);
+        let mut rng_2 = StdRng::seed_from_u64(11);
+        let samples_1 = v.sample_with_replacement_with_rng(50, &mut rng_1);
+        let samples_2 = v.sample_with_replacement_with_rng(50, &mut rng_2);
+        assert_eq!(samples_1.as_ref().map(|s| s.len()), Ok(50));
+        assert_eq!(samples_1, samples_2);
+        assert!(Vec::<f32>::new()
+            .sample_with_replacement_with_rng(1, &mut rng_1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_weighted_sampling() {
+        let v: Vec<usize> = (0..5).collect();
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut counts = [0usize; 5];
+        let num_trials = 10_000;
+        for _ in 0..num_trials {
+            let samples = v
+                .sample_weighted(2, |&&x| (x * x) as f64, &mut rng)
+                .unwrap();
+            assert_eq!(samples.len(), 2);
+            // no replacement and in population order
+            assert!(samples[0] < samples[1]);
+            for &x in samples.iter() {
+                counts[x] += 1;
+            }
+        }
+        // the element with zero weight is never drawn while there are enough
+        // elements with positive weights
+        assert_eq!(counts[0], 0);
+        for i in 1..4 {
+            assert!(counts[i] < counts[i + 1]);
+        }
+        // the element with weight 16 is drawn in the majority of the trials
+        assert!(counts[4] > num_trials * 3 / 4);
+
+        let samples = v.sample_weighted(5, |_| 1., &mut rng).unwrap();
+        assert_eq!(samples, v);
+        assert_eq!(v.sample_weighted(0, |_| 1., &mut rng), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_weighted_sampling_errors() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let v = vec![1., -2., 3.];
+        assert!(v.sample_weighted(4, |&&x| x, &mut rng).is_err());
+        assert!(v.sample_weighted(1, |&&x| x, &mut rng).is_err());
+        assert!(v.sample_weighted(1, |_| f64::NAN, &mut rng).is_err());
+        assert!(v.sample_weighted(1, |&&x: &&f64| x.abs(), &mut rng).is_ok());
+
+        let set = OrderedIntegerSet::from_slice(&[[1, 3], [10, 12]]);
+        let samples = set
+            .sample_weighted(3, |&x| if x < 10 { 0. } else { 1. }, &mut rng)
+            .unwrap();
+        assert_eq!(samples, OrderedIntegerSet::from_slice(&[[10, 12]]));
+    }
+
+    #[test]
+    fn test_sampling_with_replacement_large_population() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let v: Vec<i64> = (0..1_000_000).collect();
+        let num_samples = 100_000;
+        let samples = v.sample_with_replacement_with_rng(num_samples, &mut rng);
+        let samples = samples.unwrap();
+        assert_eq!(samples.len(), num_samples);
+        assert!(samples.iter().all(|x| (0..1_000_000).contains(x)));
+
+        // sampling must not enumerate the population, which would not fit in
+        // memory for these sets
+        let interval =
+            ContiguousIntegerSet::new(-5_000_000_000i64, 5_000_000_000);
+        let samples = interval
+            .sample_with_replacement_with_rng(5, &mut rng)
+            .unwrap();
+        assert!(samples.size() > 0 && samples.size() <= 5);
+        assert!(samples.intervals_iter().all(|i| i.is_subset_of(&interval)));
+
+        let set = OrderedIntegerSet::from_slice(&[
+            [-30_000_000_000i64, -20_000_000_000],
+            [0, 0],
+            [10_000_000_000, 40_000_000_000],
+        ]);
+        let samples =
+            set.sample_with_replacement_with_rng(5, &mut rng).unwrap();
+        assert!(samples.size() > 0 && samples.size() <= 5);
+        assert_eq!(samples.intersect(&set), samples);
+    }
+
+    #[test]
+    fn test_sampling_with_replacement_covers_population() {
+        let mut rng = StdRng::seed_from_u64(17);
+        let interval = ContiguousIntegerSet::new(-3i8, 3);
+        let samples = interval
+            .sample_with_replacement_with_rng(200, &mut rng)
+            .unwrap();
+        assert_eq!(samples, OrderedIntegerSet::from_slice(&[[-3, 3]]));
+
+        let set =
+            OrderedIntegerSet::from_slice(&[[-100i8, -99], [5, 5], [120, 122]]);
+        let samples = set.sample_with_replacement_with_rng(200, &mut rng);
+        assert_eq!(samples, Ok(set));
+
+        let empty = OrderedIntegerSet::<i32>::from_slice(&[[3, 2]]);
+        assert!(empty.sample_with_replacement_with_rng(1, &mut rng).is_err());
+        assert!(ContiguousIntegerSet::new(3, 2)
+            .sample_with_replacement_with_rng(1, &mut rng)
+            .is_err());
+    }
 }
@@ -1,10 +1,19 @@
+use rand::Rng;
 use std::{ops::Deref, slice::Iter};
 
-use crate::sample::Sample;
+use crate::sample::{sample_with_replacement_by_index, Sample};
 
-impl<'a, E: Clone> Sample<'a, Iter<'a, E>, &'a E, Vec<E>> for Vec<E> where
-    &'a E: Deref
+impl<'a, E: Clone> Sample<'a, Iter<'a, E>, &'a E, Vec<E>> for Vec<E>
+where
+    &'a E: Deref,
 {
+    fn sample_with_replacement_with_rng<'s: 'a, R: Rng>(
+        &'s self,
+        size: usize,
+        rng: &mut R,
+    ) -> Result<Vec<E>, String> {
+        sample_with_replacement_by_index(self.len(), size, rng, |i| &self[i])
+    }
 }
 
 #[cfg(test)]
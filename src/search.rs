@@ -1,3 +1,4 @@
 //! # Blanket implementations for binary search
 
 pub mod binary_search;
+pub mod interpolation_search;
@@ -1,4 +1,7 @@
-use std::{cmp::Ordering, ops::Index};
+use std::{
+    cmp::{min, Ordering},
+    ops::Index,
+};
 use Ordering::{Equal, Greater, Less};
 
 pub trait BinarySearch: Index<usize> {
@@ -21,9 +24,116 @@ pub trait BinarySearch: Index<usize> {
     ) -> Result<usize, Option<usize>>
     where
         F: Fn(&<Self as Index<usize>>::Output, &E) -> Ordering;
+
+    /// Returns the first index `i` in `[start, end)` such that the element at
+    /// `i` is not less than the `target` according to `cmp`, or `end` if
+    /// there is no such index. If there are multiple elements equal to the
+    /// `target`, the index of the first of them is returned.
+    fn lower_bound<E, F>(
+        &self,
+        start: usize,
+        end: usize,
+        target: &E,
+        cmp: F,
+    ) -> usize
+    where
+        F: Fn(&<Self as Index<usize>>::Output, &E) -> Ordering, {
+        // never reports equality so that the search ends up at the insertion
+        // point to the left of any elements equal to the target
+        match self.binary_search_with_cmp(start, end, target, |x, t| {
+            if cmp(x, t) == Less {
+                Less
+            } else {
+                Greater
+            }
+        }) {
+            Ok(i) | Err(Some(i)) => i,
+            Err(None) => start,
+        }
+    }
+
+    /// Returns the first index `i` in `[start, end)` such that the element at
+    /// `i` is greater than the `target` according to `cmp`, or `end` if
+    /// there is no such index. If there are multiple elements equal to the
+    /// `target`, the index one past the last of them is returned.
+    fn upper_bound<E, F>(
+        &self,
+        start: usize,
+        end: usize,
+        target: &E,
+        cmp: F,
+    ) -> usize
+    where
+        F: Fn(&<Self as Index<usize>>::Output, &E) -> Ordering, {
+        // never reports equality so that the search ends up at the insertion
+        // point to the right of any elements equal to the target
+        match self.binary_search_with_cmp(start, end, target, |x, t| {
+            if cmp(x, t) == Greater {
+                Greater
+            } else {
+                Less
+            }
+        }) {
+            Ok(i) | Err(Some(i)) => i,
+            Err(None) => start,
+        }
+    }
+
+    /// performs exponential search between the `start` and `end` indices,
+    /// i.e. doubles the distance of the probe from `start` until the probed
+    /// element is no longer less than the `target`, and then performs binary
+    /// search within the bracketed range. This takes `O(log i)` comparisons
+    /// where `i` is the distance of the target from `start`, which is
+    /// beneficial when the target is expected to be near the `start`.
+    /// The arguments and the returned value follow the same contract as
+    /// `binary_search_with_cmp`.
+    fn exponential_search<E, F>(
+        &self,
+        start: usize,
+        end: usize,
+        target: &E,
+        cmp: F,
+    ) -> Result<usize, Option<usize>>
+    where
+        F: Fn(&<Self as Index<usize>>::Output, &E) -> Ordering, {
+        if start >= end {
+            return Err(None);
+        }
+        let mut bound = 1;
+        while bound < end - start && cmp(&self[start + bound], target) == Less {
+            bound *= 2;
+        }
+        // all the elements before `start + bound / 2` are less than the target
+        self.binary_search_with_cmp(
+            start + bound / 2,
+            min(start + bound + 1, end),
+            target,
+            cmp,
+        )
+    }
 }
 
 impl<T> BinarySearch for Vec<T> {
+    #[inline]
+    fn binary_search_with_cmp<E, F>(
+        &self,
+        start: usize,
+        end_exclusive: usize,
+        target: &E,
+        cmp: F,
+    ) -> Result<usize, Option<usize>>
+    where
+        F: Fn(&<Self as Index<usize>>::Output, &E) -> Ordering, {
+        self.as_slice().binary_search_with_cmp(
+            start,
+            end_exclusive,
+            target,
+            cmp,
+        )
+    }
+}
+
+impl<T> BinarySearch for [T] {
     fn binary_search_with_cmp<E, F>(
         &self,
         start: usize,
@@ -254,4 +364,118 @@ mod tests {
             Ok(0)
         );
     }
+
+    #[test]
+    fn test_lower_and_upper_bound() {
+        let cmp = |x: &i32, y: &i32| x.cmp(y);
+        macro_rules! check {
+            (
+                $v:expr,
+                $start:expr,
+                $end:expr,
+                $target:expr,
+                $lower:expr,
+                $upper:expr
+            ) => {
+                assert_eq!($v.lower_bound($start, $end, &$target, cmp), $lower);
+                assert_eq!($v.upper_bound($start, $end, &$target, cmp), $upper);
+            };
+        }
+        let v = vec![1, 2, 2, 2, 3];
+        check!(v, 0, 5, 0, 0, 0);
+        check!(v, 0, 5, 1, 0, 1);
+        check!(v, 0, 5, 2, 1, 4);
+        check!(v, 0, 5, 3, 4, 5);
+        check!(v, 0, 5, 4, 5, 5);
+        check!(v, 1, 4, 2, 1, 4);
+        check!(v, 2, 4, 2, 2, 4);
+        check!(v, 2, 4, 1, 2, 2);
+        check!(v, 2, 4, 3, 4, 4);
+        check!(v, 3, 3, 2, 3, 3);
+
+        let v = vec![2, 2, 2];
+        check!(v, 0, 3, 2, 0, 3);
+        check!(v, 0, 3, 1, 0, 0);
+        check!(v, 0, 3, 3, 3, 3);
+
+        let slice = &[1, 1, 4, 4, 4, 9][..];
+        check!(slice, 0, 6, 4, 2, 5);
+        check!(slice, 0, 6, 5, 5, 5);
+        check!(slice, 0, 6, 1, 0, 2);
+        check!(slice, 0, 6, 9, 5, 6);
+    }
+
+    #[test]
+    fn test_exponential_search() {
+        let v: Vec<i64> = (0..100_000).map(|i| i * 2).collect();
+        let cmp = |x: &i64, y: &i64| x.cmp(y);
+        for target in -3..100 {
+            assert_eq!(
+                v.exponential_search(0, v.len(), &target, cmp),
+                v.binary_search_with_cmp(0, v.len(), &target, cmp)
+            );
+        }
+        assert_eq!(v.exponential_search(0, v.len(), &0, cmp), Ok(0));
+        assert_eq!(v.exponential_search(0, v.len(), &2, cmp), Ok(1));
+        assert_eq!(v.exponential_search(0, v.len(), &3, cmp), Err(Some(2)));
+        assert_eq!(v.exponential_search(0, v.len(), &64, cmp), Ok(32));
+        assert_eq!(v.exponential_search(0, v.len(), &-1, cmp), Err(Some(0)));
+        assert_eq!(v.exponential_search(0, v.len(), &199_998, cmp), Ok(99_999));
+        assert_eq!(
+            v.exponential_search(0, v.len(), &199_999, cmp),
+            Err(Some(100_000))
+        );
+        assert_eq!(v.exponential_search(10, 20, &4, cmp), Err(Some(10)));
+        assert_eq!(v.exponential_search(10, 20, &24, cmp), Ok(12));
+        assert_eq!(v.exponential_search(10, 20, &40, cmp), Err(Some(20)));
+        assert_eq!(v.exponential_search(10, 10, &20, cmp), Err(None));
+
+        // counts the comparisons made when the target is near the start
+        let num_comparisons = std::cell::Cell::new(0);
+        let counting_cmp = |x: &i64, y: &i64| {
+            num_comparisons.set(num_comparisons.get() + 1);
+            x.cmp(y)
+        };
+        assert_eq!(v.exponential_search(0, v.len(), &10, counting_cmp), Ok(5));
+        assert!(num_comparisons.get() < 10);
+    }
+
+    #[test]
+    fn test_slice_binary_search() {
+        let arr = [1, 2, 3, 6, 29, 43, 69, 100, 340];
+        let slice = &arr[..];
+        let cmp = |x: &i32, y: &i32| x.cmp(y);
+        assert_eq!(slice.binary_search_with_cmp(0, 9, &30, cmp), Err(Some(5)));
+        assert_eq!(slice.binary_search_with_cmp(0, 9, &-10, cmp), Err(Some(0)));
+        assert_eq!(
+            slice.binary_search_with_cmp(0, 9, &1000, cmp),
+            Err(Some(9))
+        );
+        assert_eq!(slice.binary_search_with_cmp(0, 9, &1, cmp), Ok(0));
+        assert_eq!(slice.binary_search_with_cmp(0, 9, &43, cmp), Ok(5));
+        assert_eq!(slice.binary_search_with_cmp(0, 9, &340, cmp), Ok(8));
+        assert_eq!(slice.binary_search_with_cmp(3, 6, &6, cmp), Ok(3));
+        assert_eq!(slice.binary_search_with_cmp(3, 6, &2, cmp), Err(Some(3)));
+        assert_eq!(slice.binary_search_with_cmp(3, 6, &69, cmp), Err(Some(6)));
+        assert_eq!(slice.binary_search_with_cmp(3, 3, &6, cmp), Err(None));
+
+        // sub-slices are indexed relative to their own start
+        let sub_slice = &arr[2..5];
+        assert_eq!(
+            sub_slice.binary_search_with_cmp(0, sub_slice.len(), &6, cmp),
+            Ok(1)
+        );
+        assert_eq!(
+            sub_slice.binary_search_with_cmp(0, sub_slice.len(), &30, cmp),
+            Err(Some(3))
+        );
+
+        let v = arr.to_vec();
+        for target in -1..350 {
+            assert_eq!(
+                slice.binary_search_with_cmp(0, slice.len(), &target, cmp),
+                v.binary_search_with_cmp(0, v.len(), &target, cmp)
+            );
+        }
+    }
 }
@@ -0,0 +1,153 @@
+use crate::search::binary_search::BinarySearch;
+use num::{Integer, ToPrimitive};
+use std::{cmp::Ordering, ops::Index};
+
+pub trait InterpolationSearch<E>:
+    BinarySearch + Index<usize, Output = E>
+where
+    E: Integer + ToPrimitive, {
+    /// performs interpolation search between the `start` and `end` indices,
+    /// where the elements in `[start, end)` are sorted in ascending order.
+    /// The position of each probe is estimated by linearly interpolating the
+    /// `target` between the smallest and the largest element of the remaining
+    /// range, which takes `O(log log n)` probes on average when the elements
+    /// are roughly uniformly distributed. If the elements are so skewed that
+    /// the interpolation fails to converge quickly, the search falls back to
+    /// `binary_search_with_cmp` on the remaining range.
+    /// `start`: start index
+    /// `end`: end index exclusive
+    /// returns the index of the target element as `Some(usize)` if present,
+    /// `Err` otherwise, following the same contract as
+    /// `BinarySearch::binary_search_with_cmp`.
+    fn interpolation_search(
+        &self,
+        start: usize,
+        end: usize,
+        target: &E,
+    ) -> Result<usize, Option<usize>> {
+        if start >= end {
+            return Err(None);
+        }
+        let mut lo = start;
+        // now hi is inclusive
+        let mut hi = end - 1;
+        if self[lo] > *target {
+            return Err(Some(lo));
+        }
+        if self[hi] < *target {
+            return Err(Some(hi + 1));
+        }
+        let target_f64 = target.to_f64().unwrap();
+        let mut probes_left = 2 * (usize::BITS - (end - start).leading_zeros());
+        // invariant: self[lo] <= target <= self[hi]
+        loop {
+            if self[lo] == self[hi] {
+                return Ok(lo);
+            }
+            if probes_left == 0 {
+                return self.binary_search_with_cmp(
+                    lo,
+                    hi + 1,
+                    target,
+                    |x, t| x.cmp(t),
+                );
+            }
+            probes_left -= 1;
+
+            let lo_f64 = self[lo].to_f64().unwrap();
+            let hi_f64 = self[hi].to_f64().unwrap();
+            let offset = ((target_f64 - lo_f64) / (hi_f64 - lo_f64)
+                * (hi - lo) as f64) as usize;
+            let probe = lo + offset.min(hi - lo);
+            match self[probe].cmp(target) {
+                Ordering::Equal => return Ok(probe),
+                Ordering::Less => {
+                    // probe < hi because self[hi] >= target
+                    lo = probe + 1;
+                    if self[lo] > *target {
+                        return Err(Some(lo));
+                    }
+                }
+                Ordering::Greater => {
+                    // probe > lo because self[lo] <= target
+                    hi = probe - 1;
+                    if self[hi] < *target {
+                        return Err(Some(hi + 1));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<E: Integer + ToPrimitive> InterpolationSearch<E> for Vec<E> {}
+
+impl<E: Integer + ToPrimitive> InterpolationSearch<E> for [E] {}
+
+#[cfg(test)]
+mod tests {
+    use super::InterpolationSearch;
+    use crate::search::binary_search::BinarySearch;
+    use rand::Rng;
+
+    #[test]
+    fn test_interpolation_search() {
+        let v = vec![1, 2, 3, 6, 29, 43, 69, 100, 340];
+        assert_eq!(v.interpolation_search(0, v.len(), &30), Err(Some(5)));
+        assert_eq!(v.interpolation_search(0, v.len(), &-10), Err(Some(0)));
+        assert_eq!(v.interpolation_search(0, v.len(), &1000), Err(Some(9)));
+        assert_eq!(v.interpolation_search(0, v.len(), &1), Ok(0));
+        assert_eq!(v.interpolation_search(0, v.len(), &29), Ok(4));
+        assert_eq!(v.interpolation_search(0, v.len(), &340), Ok(8));
+        assert_eq!(v.interpolation_search(3, 6, &6), Ok(3));
+        assert_eq!(v.interpolation_search(3, 6, &43), Ok(5));
+        assert_eq!(v.interpolation_search(3, 6, &3), Err(Some(3)));
+        assert_eq!(v.interpolation_search(3, 6, &69), Err(Some(6)));
+        assert_eq!(v.interpolation_search(3, 3, &6), Err(None));
+
+        let slice = &[4usize, 4, 4][..];
+        assert_eq!(slice.interpolation_search(0, 3, &4), Ok(0));
+        assert_eq!(slice.interpolation_search(0, 3, &3), Err(Some(0)));
+        assert_eq!(slice.interpolation_search(0, 3, &5), Err(Some(3)));
+    }
+
+    #[test]
+    fn test_interpolation_search_against_binary_search() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let len = rng.gen_range(1, 500);
+            let mut v: Vec<i64> =
+                (0..len).map(|_| rng.gen_range(-10000, 10000)).collect();
+            v.sort();
+            v.dedup();
+            for _ in 0..100 {
+                let target = rng.gen_range(-10100, 10100);
+                let start = rng.gen_range(0, v.len());
+                let end = rng.gen_range(start, v.len() + 1);
+                assert_eq!(
+                    v.interpolation_search(start, end, &target),
+                    v.binary_search_with_cmp(start, end, &target, |x, t| x
+                        .cmp(t))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolation_search_skewed() {
+        // exponentially distributed elements make the interpolation probes
+        // land far from the target
+        let v: Vec<u64> = (0..63).map(|i| 1u64 << i).collect();
+        for (i, x) in v.iter().enumerate() {
+            assert_eq!(v.interpolation_search(0, v.len(), x), Ok(i));
+            assert_eq!(
+                v.interpolation_search(0, v.len(), &(x + 1)),
+                v.binary_search_with_cmp(0, v.len(), &(x + 1), |a, b| a.cmp(b))
+            );
+        }
+        let mut v: Vec<i32> = vec![0; 1000];
+        v.push(1_000_000);
+        assert_eq!(v.interpolation_search(0, v.len(), &1_000_000), Ok(1000));
+        assert_eq!(v.interpolation_search(0, v.len(), &1), Err(Some(1000)));
+    }
+}
@@ -1,9 +1,14 @@
 use crate::{
-    interval::traits::{Coalesce, Interval},
-    set::traits::{Finite, Intersect, Refineable, Set},
+    interval::traits::{Coalesce, Interval, Topology},
+    set::traits::{Finite, Intersect, IntersectionSize, Refineable, Set},
     traits::{Slicing, ToIterator},
 };
-use num::{integer::Integer, traits::cast::ToPrimitive, FromPrimitive};
+use num::{
+    integer::Integer, traits::cast::ToPrimitive, CheckedAdd, CheckedSub,
+    FromPrimitive,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::{
     cmp::{max, min},
     ops::Range,
@@ -15,6 +20,7 @@ pub type IntegerIntervalRefinement<E> = Vec<ContiguousIntegerSet<E>>;
 /// `Ord` is automatically derived so that comparison is done lexicographically
 /// with `start` first and `end` second.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ContiguousIntegerSet<E: Integer + Copy> {
     start: E,
     end: E,
@@ -43,6 +49,54 @@ impl<E: Integer + Copy> ContiguousIntegerSet<E> {
         self.is_subset_of(&other) && (self != other) && !other.is_empty()
     }
 
+    /// Returns the integer midpoint `(start + end) / 2` rounded toward
+    /// negative infinity, e.g. the center of `[-5, -2]` is `-4`, or `None` if
+    /// the set is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::contiguous_integer_set::ContiguousIntegerSet;
+    ///
+    /// assert_eq!(ContiguousIntegerSet::new(2, 5).center(), Some(3));
+    /// assert_eq!(ContiguousIntegerSet::new(-5, -2).center(), Some(-4));
+    /// assert_eq!(ContiguousIntegerSet::new(3, 2).center(), None);
+    /// ```
+    pub fn center(&self) -> Option<E> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.midpoint())
+        }
+    }
+
+    /// Returns the number of integers strictly between the two disjoint sets,
+    /// which is `0` if they are adjacent, or `None` if the sets overlap, either
+    /// of them is empty, or the number does not fit in `E`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::contiguous_integer_set::ContiguousIntegerSet;
+    ///
+    /// let s = ContiguousIntegerSet::new(2, 5);
+    /// assert_eq!(s.gap_to(&ContiguousIntegerSet::new(9, 12)), Some(3));
+    /// assert_eq!(s.gap_to(&ContiguousIntegerSet::new(-3, 1)), Some(0));
+    /// assert_eq!(s.gap_to(&ContiguousIntegerSet::new(5, 7)), None);
+    /// ```
+    pub fn gap_to(&self, other: &ContiguousIntegerSet<E>) -> Option<E>
+    where
+        E: CheckedSub, {
+        // subtracting one from the larger bound cannot overflow
+        if self.is_empty() || other.is_empty() {
+            None
+        } else if self.end < other.start {
+            (other.start - E::one()).checked_sub(&self.end)
+        } else if other.end < self.start {
+            (self.start - E::one()).checked_sub(&other.end)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     pub fn slice<
         'a,
@@ -55,6 +109,57 @@ impl<E: Integer + Copy> ContiguousIntegerSet<E> {
     }
 }
 
+impl<E> ContiguousIntegerSet<E>
+where
+    E: Integer + Copy + CheckedAdd + FromPrimitive + ToPrimitive,
+{
+    /// Splits the set into consecutive sub-intervals of `chunk_size` elements
+    /// each, where the final chunk holds the remaining elements. Returns an
+    /// empty vector if the set is empty.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::contiguous_integer_set::ContiguousIntegerSet;
+    ///
+    /// assert_eq!(ContiguousIntegerSet::new(0, 9).chunks(3), vec![
+    ///     ContiguousIntegerSet::new(0, 2),
+    ///     ContiguousIntegerSet::new(3, 5),
+    ///     ContiguousIntegerSet::new(6, 8),
+    ///     ContiguousIntegerSet::new(9, 9),
+    /// ]);
+    /// ```
+    pub fn chunks(&self, chunk_size: usize) -> Vec<ContiguousIntegerSet<E>> {
+        assert!(chunk_size > 0, "chunk_size must be positive");
+        if self.is_empty() {
+            return Vec::new();
+        }
+        // a chunk size that does not fit in `E` covers the entire set
+        let step = match E::from_usize(chunk_size) {
+            None => return vec![*self],
+            Some(step) => step,
+        };
+        let mut chunks = Vec::new();
+        let mut chunk_start = self.start;
+        loop {
+            match chunk_start.checked_add(&step) {
+                Some(next) if next <= self.end => {
+                    chunks.push(ContiguousIntegerSet::new(
+                        chunk_start,
+                        next - E::one(),
+                    ));
+                    chunk_start = next;
+                }
+                _ => break,
+            }
+        }
+        chunks.push(ContiguousIntegerSet::new(chunk_start, self.end));
+        chunks
+    }
+}
+
 impl<E: Integer + Copy> Set<E> for ContiguousIntegerSet<E> {
     #[inline]
     fn is_empty(&self) -> bool {
@@ -122,6 +227,25 @@ impl<E: Integer + Copy>
     }
 }
 
+impl<E: Integer + Copy + ToPrimitive> IntersectionSize<&ContiguousIntegerSet<E>>
+    for ContiguousIntegerSet<E>
+{
+    /// # Example
+    /// ```
+    /// use math::set::{
+    ///     contiguous_integer_set::ContiguousIntegerSet, traits::IntersectionSize,
+    /// };
+    ///
+    /// let s = ContiguousIntegerSet::new(2, 8);
+    /// assert_eq!(s.intersection_size(&ContiguousIntegerSet::new(5, 12)), 4);
+    /// assert_eq!(s.intersection_size(&ContiguousIntegerSet::new(9, 12)), 0);
+    /// ```
+    #[inline]
+    fn intersection_size(&self, other: &ContiguousIntegerSet<E>) -> usize {
+        self.intersect(other).map_or(0, |i| i.size())
+    }
+}
+
 /// returns an interval if only if the two intervals can be merged into
 /// a single non-empty interval.
 /// An empty interval can be merged with any other non-empty interval
@@ -134,8 +258,9 @@ impl<E: Integer + Copy> Coalesce<Self> for ContiguousIntegerSet<E> {
         } else if other.is_empty() {
             Some(*self)
         } else {
-            if self.start > other.end + E::one()
-                || self.end + E::one() < other.start
+            // subtracting one from the larger bound cannot overflow
+            if (self.start > other.end && self.start - E::one() > other.end)
+                || (other.start > self.end && other.start - E::one() > self.end)
             {
                 None
             } else {
@@ -148,6 +273,19 @@ impl<E: Integer + Copy> Coalesce<Self> for ContiguousIntegerSet<E> {
     }
 }
 
+/// `ContiguousIntegerSet` always includes both of its endpoints
+impl<E: Integer + Copy> Topology for ContiguousIntegerSet<E> {
+    #[inline]
+    fn is_open(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn is_closed(&self) -> bool {
+        true
+    }
+}
+
 impl<E: Integer + Copy + ToPrimitive> Finite for ContiguousIntegerSet<E> {
     fn size(&self) -> usize {
         if self.start > self.end {
@@ -301,8 +439,12 @@ impl<E: Integer + Copy> Iterator for ContiguousIntegerSetIter<E> {
 
 #[cfg(test)]
 mod tests {
-    use crate::set::{
-        contiguous_integer_set::ContiguousIntegerSet, traits::Intersect,
+    use crate::{
+        interval::traits::Interval,
+        set::{
+            contiguous_integer_set::ContiguousIntegerSet,
+            traits::{Finite, Intersect, IntersectionSize},
+        },
     };
 
     #[test]
@@ -350,6 +492,28 @@ mod tests {
         assert!(!s1.has_non_empty_intersection_with(&s4));
     }
 
+    #[test]
+    fn test_intersection_size() {
+        let sets = [
+            ContiguousIntegerSet::new(2, 5),
+            ContiguousIntegerSet::new(2, 2),
+            ContiguousIntegerSet::new(4, 8),
+            ContiguousIntegerSet::new(-3, -1),
+            ContiguousIntegerSet::new(-5, 20),
+            ContiguousIntegerSet::new(3, 1),
+        ];
+        for a in sets.iter() {
+            for b in sets.iter() {
+                assert_eq!(
+                    a.intersection_size(b),
+                    a.intersect(b).map_or(0, |i| i.size())
+                );
+            }
+        }
+        assert_eq!(sets[0].intersection_size(&sets[2]), 2);
+        assert_eq!(sets[4].intersection_size(&sets[0]), 4);
+    }
+
     #[test]
     fn test_is_subset_of() {
         macro_rules! ab_is_subset_of_cd {
@@ -714,4 +878,188 @@ mod tests {
         test_nonnegative_abcd!(10, 2, 10, 2, true, false);
         test_nonnegative_abcd!(10, 2, 10, 4, true, false);
     }
+
+    #[test]
+    fn test_midpoint() {
+        assert_eq!(ContiguousIntegerSet::new(2, 6).midpoint(), 4);
+        assert_eq!(ContiguousIntegerSet::new(2, 7).midpoint(), 4);
+        assert_eq!(ContiguousIntegerSet::new(3, 3).midpoint(), 3);
+        assert_eq!(ContiguousIntegerSet::new(-6, -2).midpoint(), -4);
+        assert_eq!(ContiguousIntegerSet::new(-3, 0).midpoint(), -2);
+        assert_eq!(ContiguousIntegerSet::new(2usize, 6).midpoint(), 4);
+    }
+
+    #[test]
+    fn test_center() {
+        assert_eq!(ContiguousIntegerSet::new(2, 5).center(), Some(3));
+        assert_eq!(ContiguousIntegerSet::new(2, 6).center(), Some(4));
+        assert_eq!(ContiguousIntegerSet::new(4, 4).center(), Some(4));
+        assert_eq!(ContiguousIntegerSet::new(-5, -2).center(), Some(-4));
+        assert_eq!(ContiguousIntegerSet::new(-6, -2).center(), Some(-4));
+        assert_eq!(ContiguousIntegerSet::new(-3, 0).center(), Some(-2));
+        assert_eq!(ContiguousIntegerSet::new(-3, 2).center(), Some(-1));
+        assert_eq!(ContiguousIntegerSet::new(1u8, 254).center(), Some(127));
+        assert_eq!(ContiguousIntegerSet::new(5, 4).center(), None);
+        assert_eq!(ContiguousIntegerSet::new(-2, -5).center(), None);
+    }
+
+    #[test]
+    fn test_gap_to() {
+        fn test(a: (i32, i32), b: (i32, i32), expected: Option<i32>) {
+            let s1 = ContiguousIntegerSet::new(a.0, a.1);
+            let s2 = ContiguousIntegerSet::new(b.0, b.1);
+            assert_eq!(s1.gap_to(&s2), expected);
+            assert_eq!(s2.gap_to(&s1), expected);
+        }
+        // overlapping
+        test((2, 5), (5, 7), None);
+        test((2, 5), (3, 4), None);
+        test((2, 5), (-1, 10), None);
+        test((2, 2), (2, 2), None);
+        // adjacent
+        test((2, 5), (6, 7), Some(0));
+        test((-3, -1), (0, 0), Some(0));
+        // gapped
+        test((2, 5), (7, 7), Some(1));
+        test((2, 5), (10, 12), Some(4));
+        test((-10, -8), (8, 10), Some(15));
+        // empty
+        test((2, 5), (8, 7), None);
+        test((5, 2), (8, 10), None);
+
+        assert_eq!(
+            ContiguousIntegerSet::new(0u8, 0)
+                .gap_to(&ContiguousIntegerSet::new(255, 255)),
+            Some(254)
+        );
+        // the gap does not fit in the type
+        let min = ContiguousIntegerSet::new(-128i8, -128);
+        let max = ContiguousIntegerSet::new(127i8, 127);
+        assert_eq!(min.gap_to(&max), None);
+        assert_eq!(max.gap_to(&min), None);
+        assert_eq!(min.gap_to(&ContiguousIntegerSet::new(0, 0)), Some(127));
+        assert_eq!(max.gap_to(&ContiguousIntegerSet::new(-1, -1)), Some(127));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let s = ContiguousIntegerSet::new(2, 6);
+        assert!(s.overlaps(&ContiguousIntegerSet::new(6, 9)));
+        assert!(s.overlaps(&ContiguousIntegerSet::new(0, 2)));
+        assert!(s.overlaps(&ContiguousIntegerSet::new(3, 4)));
+        assert!(s.overlaps(&ContiguousIntegerSet::new(-1, 10)));
+        assert!(!s.overlaps(&ContiguousIntegerSet::new(7, 9)));
+        assert!(!s.overlaps(&ContiguousIntegerSet::new(0, 1)));
+        assert!(!s.overlaps(&ContiguousIntegerSet::new(4, 3)));
+    }
+
+    #[test]
+    fn test_clamp_to() {
+        fn test(
+            a: (i32, i32),
+            bound: (i32, i32),
+            expected: Option<(i32, i32)>,
+        ) {
+            assert_eq!(
+                ContiguousIntegerSet::new(a.0, a.1)
+                    .clamp_to(&ContiguousIntegerSet::new(bound.0, bound.1)),
+                expected.map(|(s, e)| ContiguousIntegerSet::new(s, e))
+            );
+        }
+        test((2, 6), (0, 10), Some((2, 6)));
+        test((-5, 3), (0, 10), Some((0, 3)));
+        test((8, 15), (0, 10), Some((8, 10)));
+        test((-5, 15), (0, 10), Some((0, 10)));
+        test((10, 15), (0, 10), Some((10, 10)));
+        test((11, 15), (0, 10), None);
+        test((-3, -1), (0, 10), None);
+        test((4, 3), (0, 10), None);
+        test((2, 6), (10, 0), None);
+    }
+
+    #[test]
+    fn test_chunks() {
+        fn test(
+            start: i32,
+            end: i32,
+            chunk_size: usize,
+            expected: &[[i32; 2]],
+        ) {
+            let expected: Vec<ContiguousIntegerSet<i32>> = expected
+                .iter()
+                .map(|[a, b]| ContiguousIntegerSet::new(*a, *b))
+                .collect();
+            assert_eq!(
+                ContiguousIntegerSet::new(start, end).chunks(chunk_size),
+                expected
+            );
+        }
+        test(0, 9, 3, &[[0, 2], [3, 5], [6, 8], [9, 9]]);
+        test(0, 8, 3, &[[0, 2], [3, 5], [6, 8]]);
+        test(-4, 3, 5, &[[-4, 0], [1, 3]]);
+        test(0, 9, 1, &[
+            [0, 0],
+            [1, 1],
+            [2, 2],
+            [3, 3],
+            [4, 4],
+            [5, 5],
+            [6, 6],
+            [7, 7],
+            [8, 8],
+            [9, 9],
+        ]);
+        test(0, 9, 10, &[[0, 9]]);
+        test(0, 9, 100, &[[0, 9]]);
+        test(5, 5, 2, &[[5, 5]]);
+        test(5, 4, 2, &[]);
+
+        // does not overflow at the maximum of the type
+        assert_eq!(ContiguousIntegerSet::new(250u8, 255).chunks(4), vec![
+            ContiguousIntegerSet::new(250, 253),
+            ContiguousIntegerSet::new(254, 255),
+        ]);
+        assert_eq!(ContiguousIntegerSet::new(250u8, 253).chunks(4), vec![
+            ContiguousIntegerSet::new(250, 253)
+        ]);
+        assert_eq!(ContiguousIntegerSet::new(0u8, 255).chunks(128), vec![
+            ContiguousIntegerSet::new(0, 127),
+            ContiguousIntegerSet::new(128, 255),
+        ]);
+        assert_eq!(ContiguousIntegerSet::new(0u8, 255).chunks(1000), vec![
+            ContiguousIntegerSet::new(0, 255)
+        ]);
+
+        // does not overflow on a signed range wider than the maximum
+        let chunks = ContiguousIntegerSet::new(-100i8, 100).chunks(3);
+        assert_eq!(chunks.len(), 67);
+        assert_eq!(chunks[0], ContiguousIntegerSet::new(-100, -98));
+        assert_eq!(chunks[66], ContiguousIntegerSet::new(98, 100));
+        assert!(chunks
+            .windows(2)
+            .all(|w| w[0].get_end() + 1 == w[1].get_start()));
+        assert_eq!(ContiguousIntegerSet::new(-128i8, 127).chunks(100), vec![
+            ContiguousIntegerSet::new(-128, -29),
+            ContiguousIntegerSet::new(-28, 71),
+            ContiguousIntegerSet::new(72, 127),
+        ]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunks_zero_size() {
+        ContiguousIntegerSet::new(0, 9).chunks(0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let set = ContiguousIntegerSet::new(-3i32, 7);
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, r#"{"start":-3,"end":7}"#);
+        assert_eq!(
+            serde_json::from_str::<ContiguousIntegerSet<i32>>(&json).unwrap(),
+            set
+        );
+    }
 }
@@ -1,24 +1,35 @@
 use crate::{
     interval::traits::{Coalesce, CoalesceIntervals, Interval},
-    sample::Sample,
+    sample::{sample_with_replacement_by_index, Sample},
     search::binary_search::BinarySearch,
     set::{
         contiguous_integer_set::{
             ContiguousIntegerSet, ContiguousIntegerSetIter,
         },
-        traits::{Finite, Intersect, Set},
+        traits::{Finite, Intersect, IntersectionSize, Set},
     },
     traits::{Collecting, Slicing, ToIterator},
 };
 use num::{integer::Integer, traits::cast::ToPrimitive, FromPrimitive};
+use rand::Rng;
+use rayon::{
+    iter::{IntoParallelIterator, ParallelIterator},
+    slice::{ParallelSlice, ParallelSliceMut},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
-    cmp::{min, Ordering},
+    cmp::{max, min, Ordering},
     iter::Sum,
     ops::Range,
 };
 
 pub mod arithmetic;
 
+/// The minimum number of intervals coalesced by each parallel task in
+/// `from_contiguous_integer_sets_par`
+const MIN_PARALLEL_CHUNK_SIZE: usize = 1 << 12;
+
 /// An `OrderedIntegerSet` consists of a sequence of `ContiguousIntegerSet` that
 /// are sorted in ascending order where successive intervals are not
 /// coalesceable, i.e., if intervals A and B are successive intervals, then
@@ -119,6 +130,28 @@ impl<E: Integer + Copy + ToPrimitive> OrderedIntegerSet<E> {
         None
     }
 
+    /// Returns the contiguous interval `[first(), last()]` spanning the whole
+    /// set including the gaps, or `None` if the set is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::{
+    ///     contiguous_integer_set::ContiguousIntegerSet,
+    ///     ordered_integer_set::OrderedIntegerSet,
+    /// };
+    ///
+    /// let set = OrderedIntegerSet::from_slice(&[[1, 3], [7, 9], [12, 12]]);
+    /// assert_eq!(
+    ///     set.bounding_interval(),
+    ///     Some(ContiguousIntegerSet::new(1, 12))
+    /// );
+    /// assert_eq!(OrderedIntegerSet::<i32>::new().bounding_interval(), None);
+    /// ```
+    pub fn bounding_interval(&self) -> Option<ContiguousIntegerSet<E>> {
+        self.first_and_last()
+            .map(|(first, last)| ContiguousIntegerSet::new(first, last))
+    }
+
     /// The `slicer` can be any struct that implements the `Slicing` trait.
     /// For example, the `Slicing` trait has been implemented for the
     /// `Range<usize>` struct.
@@ -150,7 +183,7 @@ impl<E: Integer + Copy + ToPrimitive> OrderedIntegerSet<E> {
 
     #[inline]
     pub fn remove_empty_intervals(&mut self) {
-        let _ = self.intervals.extract_if(|i| i.is_empty());
+        self.intervals.retain(|i| !i.is_empty());
     }
 
     #[inline]
@@ -168,10 +201,373 @@ impl<E: Integer + Copy + ToPrimitive> OrderedIntegerSet<E> {
         self.intervals.iter()
     }
 
+    /// Iterates through the elements in increasing order by borrowing the
+    /// intervals, unlike `to_iter`, which clones the set into the iterator.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::ordered_integer_set::OrderedIntegerSet;
+    ///
+    /// let set = OrderedIntegerSet::from_slice(&[[2, 4], [6, 7]]);
+    /// assert_eq!(set.iter().collect::<Vec<i32>>(), vec![2, 3, 4, 6, 7]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> IntegerSetRefIter<'_, E> {
+        IntegerSetRefIter {
+            intervals: &self.intervals,
+            current_interval_index: 0,
+            current_element_index: E::zero(),
+        }
+    }
+
+    /// Calls `f` on the non-empty intersection of each pair of intervals from
+    /// `self` and `other`, in increasing order.
+    fn for_each_intersecting_interval<F>(
+        &self,
+        other: &OrderedIntegerSet<E>,
+        mut f: F,
+    ) where
+        F: FnMut(ContiguousIntegerSet<E>), {
+        let rhs_intervals = &other.intervals;
+        let rhs_len = rhs_intervals.len();
+        let mut j = 0;
+        for interval in self.intervals.iter() {
+            while j < rhs_len
+                && rhs_intervals[j].get_end() < interval.get_start()
+            {
+                j += 1;
+            }
+            while j < rhs_len
+                && rhs_intervals[j].get_start() <= interval.get_end()
+            {
+                let rhs_interval = &rhs_intervals[j];
+                if let Some(i) = interval.intersect(rhs_interval) {
+                    f(i);
+                }
+                if rhs_interval.get_end() <= interval.get_end() {
+                    j += 1;
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+
     #[inline]
     pub fn num_intervals(&self) -> usize {
         self.intervals.len()
     }
+
+    /// Inserts all the integers in the `interval` into the set, coalescing the
+    /// `interval` with all the existing intervals that overlap or are adjacent
+    /// to it.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::{
+    ///     contiguous_integer_set::ContiguousIntegerSet,
+    ///     ordered_integer_set::OrderedIntegerSet,
+    /// };
+    ///
+    /// let mut set = OrderedIntegerSet::from_slice(&[[1, 3], [6, 8], [12, 15]]);
+    /// set.insert_interval(ContiguousIntegerSet::new(4, 5));
+    /// assert_eq!(set, OrderedIntegerSet::from_slice(&[[1, 8], [12, 15]]));
+    /// ```
+    pub fn insert_interval(&mut self, interval: ContiguousIntegerSet<E>) {
+        if interval.is_empty() {
+            return;
+        }
+        let cmp = |existing: &ContiguousIntegerSet<E>,
+                   interval: &ContiguousIntegerSet<E>| {
+            // subtracting one from the larger bound cannot overflow
+            if existing.get_end() < interval.get_start()
+                && existing.get_end() < interval.get_start() - E::one()
+            {
+                Ordering::Less
+            } else if existing.get_start() > interval.get_end()
+                && existing.get_start() - E::one() > interval.get_end()
+            {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        };
+        // the intervals in [first, last) can be coalesced with the `interval`
+        let n = self.intervals.len();
+        let first = self.intervals.lower_bound(0, n, &interval, cmp);
+        let last = self.intervals.upper_bound(first, n, &interval, cmp);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |acc, i| acc.coalesce_with(i).unwrap());
+        self.intervals.splice(first..last, std::iter::once(merged));
+    }
+
+    /// Removes the `element` from the set, splitting the interval containing it
+    /// if the `element` is in the middle of the interval. Returns whether the
+    /// `element` was present.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::ordered_integer_set::OrderedIntegerSet;
+    ///
+    /// let mut set = OrderedIntegerSet::from_slice(&[[1, 5]]);
+    /// assert!(set.remove(3));
+    /// assert_eq!(set, OrderedIntegerSet::from_slice(&[[1, 2], [4, 5]]));
+    /// assert!(!set.remove(3));
+    /// ```
+    pub fn remove(&mut self, element: E) -> bool {
+        let i = match self.intervals.binary_search_with_cmp(
+            0,
+            self.intervals.len(),
+            &element,
+            |interval, element| {
+                if interval.get_end() < *element {
+                    Ordering::Less
+                } else if interval.get_start() > *element {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            },
+        ) {
+            Ok(i) => i,
+            Err(_) => return false,
+        };
+        let (start, end) = self.intervals[i].get_start_and_end();
+        if start == end {
+            self.intervals.remove(i);
+        } else if element == start {
+            self.intervals[i] =
+                ContiguousIntegerSet::new(start + E::one(), end);
+        } else if element == end {
+            self.intervals[i] =
+                ContiguousIntegerSet::new(start, end - E::one());
+        } else {
+            self.intervals[i] =
+                ContiguousIntegerSet::new(start, element - E::one());
+            self.intervals.insert(
+                i + 1,
+                ContiguousIntegerSet::new(element + E::one(), end),
+            );
+        }
+        true
+    }
+
+    /// Applies `f` to each of the intervals and creates a new set from the
+    /// results. The resulting intervals are re-sorted and re-coalesced, since
+    /// the mapping can reorder intervals or make them overlap.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::{
+    ///     contiguous_integer_set::ContiguousIntegerSet,
+    ///     ordered_integer_set::OrderedIntegerSet,
+    /// };
+    ///
+    /// let set = OrderedIntegerSet::from_slice(&[[1, 3], [6, 8]]);
+    /// let widened = set.map_intervals(|i| {
+    ///     let (start, end) = i.get_start_and_end();
+    ///     ContiguousIntegerSet::new(start - 1, end + 1)
+    /// });
+    /// assert_eq!(widened, OrderedIntegerSet::from_slice(&[[0, 9]]));
+    /// ```
+    pub fn map_intervals<F>(&self, f: F) -> OrderedIntegerSet<E>
+    where
+        F: Fn(ContiguousIntegerSet<E>) -> ContiguousIntegerSet<E>, {
+        OrderedIntegerSet::from_contiguous_integer_sets(
+            self.intervals.iter().map(|&i| f(i)).collect(),
+        )
+    }
+
+    /// Returns the integers strictly between consecutive intervals, i.e. the
+    /// complement of the set within `[first(), last()]`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::ordered_integer_set::OrderedIntegerSet;
+    ///
+    /// let set = OrderedIntegerSet::from_slice(&[[1, 3], [7, 9], [11, 12]]);
+    /// assert_eq!(
+    ///     set.gaps(),
+    ///     OrderedIntegerSet::from_slice(&[[4, 6], [10, 10]])
+    /// );
+    /// ```
+    pub fn gaps(&self) -> OrderedIntegerSet<E> {
+        let intervals = self.to_non_empty_intervals().intervals;
+        let gaps = intervals
+            .windows(2)
+            .map(|pair| {
+                ContiguousIntegerSet::new(
+                    pair[0].get_end() + E::one(),
+                    pair[1].get_start() - E::one(),
+                )
+            })
+            .filter(|gap| !gap.is_empty())
+            .collect();
+        OrderedIntegerSet::from_ordered_coalesced_contiguous_integer_sets(gaps)
+    }
+
+    /// Keeps only the elements satisfying `pred`, rebuilding the intervals
+    /// from the retained elements such that adjacent elements are coalesced.
+    ///
+    /// Note that `pred` is evaluated on every element of the set, so the cost
+    /// is linear in the number of elements rather than in the number of
+    /// intervals, and the resulting set can be heavily fragmented, e.g.
+    /// retaining the even numbers turns every interval into singletons.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::ordered_integer_set::OrderedIntegerSet;
+    ///
+    /// let mut set = OrderedIntegerSet::from_slice(&[[0, 5], [8, 9]]);
+    /// set.retain(|x| x != 3 && x != 8);
+    /// assert_eq!(
+    ///     set,
+    ///     OrderedIntegerSet::from_slice(&[[0, 2], [4, 5], [9, 9]])
+    /// );
+    /// ```
+    pub fn retain<F: Fn(E) -> bool>(&mut self, pred: F) {
+        let mut intervals: Vec<ContiguousIntegerSet<E>> = Vec::new();
+        for x in self.iter().filter(|&x| pred(x)) {
+            match intervals.last_mut() {
+                Some(last) if last.get_end() + E::one() == x => {
+                    *last = ContiguousIntegerSet::new(last.get_start(), x);
+                }
+                _ => intervals.push(ContiguousIntegerSet::new(x, x)),
+            }
+        }
+        self.intervals = intervals;
+    }
+}
+
+impl<E> OrderedIntegerSet<E>
+where
+    E: Integer + Copy + ToPrimitive + Send + Sync,
+{
+    /// Same as `from_contiguous_integer_sets` except that the intervals are
+    /// sorted in parallel and then coalesced through a parallel reduction,
+    /// where each chunk of the sorted intervals is coalesced independently
+    /// and the adjacent coalesced runs are merged.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::{
+    ///     contiguous_integer_set::ContiguousIntegerSet,
+    ///     ordered_integer_set::OrderedIntegerSet,
+    /// };
+    ///
+    /// let intervals = vec![
+    ///     ContiguousIntegerSet::new(6, 9),
+    ///     ContiguousIntegerSet::new(1, 3),
+    ///     ContiguousIntegerSet::new(4, 4),
+    ///     ContiguousIntegerSet::new(12, 15),
+    /// ];
+    /// assert_eq!(
+    ///     OrderedIntegerSet::from_contiguous_integer_sets_par(intervals),
+    ///     OrderedIntegerSet::from_slice(&[[1, 4], [6, 9], [12, 15]])
+    /// );
+    /// ```
+    pub fn from_contiguous_integer_sets_par(
+        sets: Vec<ContiguousIntegerSet<E>>,
+    ) -> OrderedIntegerSet<E> {
+        let mut sets: Vec<ContiguousIntegerSet<E>> =
+            sets.into_par_iter().filter(|s| !s.is_empty()).collect();
+        sets.par_sort_unstable_by_key(|s| s.get_start());
+        let chunk_size = max(
+            sets.len() / rayon::current_num_threads(),
+            MIN_PARALLEL_CHUNK_SIZE,
+        );
+        let intervals = sets
+            .par_chunks(chunk_size)
+            .map(|chunk| chunk.to_vec().into_coalesced())
+            .reduce(Vec::new, |mut lhs, rhs| {
+                // the intervals in `rhs` start no earlier than the last
+                // interval in `lhs`, so only the leading intervals of `rhs`
+                // can be coalesced with it
+                let mut rhs = rhs.into_iter().peekable();
+                if let Some(last) = lhs.last_mut() {
+                    while let Some(merged) =
+                        rhs.peek().and_then(|i| last.coalesce_with(i))
+                    {
+                        *last = merged;
+                        rhs.next();
+                    }
+                }
+                lhs.extend(rhs);
+                lhs
+            });
+        OrderedIntegerSet {
+            intervals,
+        }
+    }
+}
+
+impl<E: Integer + Copy + Sum + ToPrimitive> OrderedIntegerSet<E> {
+    /// Returns the Jaccard similarity `|A ∩ B| / |A ∪ B|` between the two
+    /// sets, or 0 if both of the sets are empty. The size of the union is
+    /// obtained as `|A| + |B| - |A ∩ B|`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::ordered_integer_set::OrderedIntegerSet;
+    ///
+    /// let a = OrderedIntegerSet::from_slice(&[[1, 4]]);
+    /// let b = OrderedIntegerSet::from_slice(&[[3, 6]]);
+    /// assert_eq!(a.jaccard(&b), 2. / 6.);
+    /// ```
+    pub fn jaccard(&self, other: &OrderedIntegerSet<E>) -> f64 {
+        let intersection_size = self.intersect(other).size();
+        let union_size = self.size() + other.size() - intersection_size;
+        if union_size == 0 {
+            0.
+        } else {
+            intersection_size as f64 / union_size as f64
+        }
+    }
+
+    /// Returns the fraction of the `bound` covered by the set, i.e.
+    /// `|self ∩ bound| / |bound|`, or 0 if the `bound` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::{
+    ///     contiguous_integer_set::ContiguousIntegerSet,
+    ///     ordered_integer_set::OrderedIntegerSet,
+    /// };
+    ///
+    /// let set = OrderedIntegerSet::from_slice(&[[0, 2], [8, 12]]);
+    /// let bound = ContiguousIntegerSet::new(0, 9);
+    /// assert_eq!(set.coverage_ratio(&bound), 0.5);
+    /// ```
+    pub fn coverage_ratio(&self, bound: &ContiguousIntegerSet<E>) -> f64 {
+        let bound_size = bound.size();
+        if bound_size == 0 {
+            0.
+        } else {
+            self.intersect(bound).size() as f64 / bound_size as f64
+        }
+    }
+
+    /// Splits the set into the first `index` elements and the rest, i.e.
+    /// `(self.slice(0..index), self.slice(index..self.size()))`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::ordered_integer_set::OrderedIntegerSet;
+    ///
+    /// let set = OrderedIntegerSet::from_slice(&[[0, 4], [10, 12]]);
+    /// let (left, right) = set.split_at(3);
+    /// assert_eq!(left, OrderedIntegerSet::from_slice(&[[0, 2]]));
+    /// assert_eq!(right, OrderedIntegerSet::from_slice(&[[3, 4], [10, 12]]));
+    /// ```
+    pub fn split_at(
+        &self,
+        index: usize,
+    ) -> (OrderedIntegerSet<E>, OrderedIntegerSet<E>)
+    where
+        E: FromPrimitive + std::fmt::Debug, {
+        (self.slice(0..index), self.slice(index..self.size()))
+    }
 }
 
 impl<E: Integer + Copy + Sum + ToPrimitive> Finite for OrderedIntegerSet<E> {
@@ -179,6 +575,12 @@ impl<E: Integer + Copy + Sum + ToPrimitive> Finite for OrderedIntegerSet<E> {
     fn size(&self) -> usize {
         self.intervals.iter().map(|&i| i.size()).sum()
     }
+
+    /// Stops at the first non-empty interval without summing the sizes.
+    #[inline]
+    fn is_empty_set(&self) -> bool {
+        self.intervals.iter().all(|i| i.is_empty())
+    }
 }
 
 impl<E: Integer + Copy + ToPrimitive> From<Vec<ContiguousIntegerSet<E>>>
@@ -192,6 +594,36 @@ impl<E: Integer + Copy + ToPrimitive> From<Vec<ContiguousIntegerSet<E>>>
     }
 }
 
+/// Serialized as the sequence of its intervals
+#[cfg(feature = "serde")]
+impl<E> Serialize for OrderedIntegerSet<E>
+where
+    E: Integer + Copy + ToPrimitive + Serialize,
+{
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        self.intervals.serialize(serializer)
+    }
+}
+
+/// The deserialized intervals are sorted and coalesced instead of being
+/// trusted to satisfy the invariants of an `OrderedIntegerSet`.
+#[cfg(feature = "serde")]
+impl<'de, E> Deserialize<'de> for OrderedIntegerSet<E>
+where
+    E: Integer + Copy + ToPrimitive + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let intervals =
+            Vec::<ContiguousIntegerSet<E>>::deserialize(deserializer)?;
+        Ok(OrderedIntegerSet::from_contiguous_integer_sets(intervals))
+    }
+}
+
 impl<E: Integer + Copy + ToPrimitive> Set<E> for OrderedIntegerSet<E> {
     #[inline]
     fn is_empty(&self) -> bool {
@@ -225,29 +657,7 @@ where
 {
     fn intersect(&self, other: &OrderedIntegerSet<E>) -> OrderedIntegerSet<E> {
         let mut intersection = Vec::new();
-        let rhs_intervals = &other.intervals;
-        let rhs_len = rhs_intervals.len();
-        let mut j = 0;
-        for interval in self.intervals.iter() {
-            while j < rhs_len
-                && rhs_intervals[j].get_end() < interval.get_start()
-            {
-                j += 1;
-            }
-            while j < rhs_len
-                && rhs_intervals[j].get_start() <= interval.get_end()
-            {
-                let rhs_interval = &rhs_intervals[j];
-                if let Some(i) = interval.intersect(rhs_interval) {
-                    intersection.push(i);
-                }
-                if rhs_interval.get_end() <= interval.get_end() {
-                    j += 1;
-                } else {
-                    break;
-                }
-            }
-        }
+        self.for_each_intersecting_interval(other, |i| intersection.push(i));
         OrderedIntegerSet::from_contiguous_integer_sets(intersection)
     }
 
@@ -259,6 +669,49 @@ where
     }
 }
 
+impl<E> IntersectionSize<&OrderedIntegerSet<E>> for OrderedIntegerSet<E>
+where
+    E: Integer + Copy + ToPrimitive,
+{
+    /// # Example
+    /// ```
+    /// use math::set::{
+    ///     ordered_integer_set::OrderedIntegerSet, traits::IntersectionSize,
+    /// };
+    ///
+    /// let a = OrderedIntegerSet::from_slice(&[[1, 4], [8, 10]]);
+    /// let b = OrderedIntegerSet::from_slice(&[[3, 9]]);
+    /// assert_eq!(a.intersection_size(&b), 4);
+    /// ```
+    fn intersection_size(&self, other: &OrderedIntegerSet<E>) -> usize {
+        let mut size = 0;
+        self.for_each_intersecting_interval(other, |i| size += i.size());
+        size
+    }
+}
+
+impl<E> IntersectionSize<&ContiguousIntegerSet<E>> for OrderedIntegerSet<E>
+where
+    E: Integer + Copy + ToPrimitive,
+{
+    fn intersection_size(&self, other: &ContiguousIntegerSet<E>) -> usize {
+        self.intervals
+            .iter()
+            .map(|interval| interval.intersection_size(other))
+            .sum()
+    }
+}
+
+impl<E> IntersectionSize<&OrderedIntegerSet<E>> for ContiguousIntegerSet<E>
+where
+    E: Integer + Copy + ToPrimitive,
+{
+    #[inline]
+    fn intersection_size(&self, other: &OrderedIntegerSet<E>) -> usize {
+        other.intersection_size(self)
+    }
+}
+
 impl<E> Intersect<&ContiguousIntegerSet<E>, OrderedIntegerSet<E>>
     for OrderedIntegerSet<E>
 where
@@ -388,6 +841,27 @@ impl<E: Integer + Copy + ToPrimitive> Collecting<E> for OrderedIntegerSet<E> {
             }
         };
     }
+
+    /// Sorts the `items` and groups consecutive integers into intervals, which
+    /// are then coalesced with the existing intervals all at once.
+    fn collect_all<I: IntoIterator<Item = E>>(&mut self, items: I) {
+        let mut items: Vec<E> = items.into_iter().collect();
+        if items.is_empty() {
+            return;
+        }
+        items.sort();
+        let mut start = items[0];
+        let mut end = items[0];
+        for &item in items.iter().skip(1) {
+            if item > end + E::one() {
+                self.intervals.push(ContiguousIntegerSet::new(start, end));
+                start = item;
+            }
+            end = item;
+        }
+        self.intervals.push(ContiguousIntegerSet::new(start, end));
+        self.intervals.coalesce_intervals_inplace();
+    }
 }
 
 impl<E> Slicing<&OrderedIntegerSet<E>, OrderedIntegerSet<E>> for Range<usize>
@@ -431,11 +905,35 @@ where
     }
 }
 
-impl<E> Sample<'_, ContiguousIntegerSetIter<E>, E, OrderedIntegerSet<E>>
+impl<'a, E> Sample<'a, ContiguousIntegerSetIter<E>, E, OrderedIntegerSet<E>>
     for ContiguousIntegerSet<E>
 where
     E: Integer + Copy + ToPrimitive,
 {
+    fn sample_with_replacement_with_rng<'s: 'a, R: Rng>(
+        &'s self,
+        size: usize,
+        rng: &mut R,
+    ) -> Result<OrderedIntegerSet<E>, String> {
+        sample_with_replacement_by_index(self.size(), size, rng, |i| {
+            self.get_start() + offset_to_integer(i)
+        })
+    }
+}
+
+/// Converts the `offset` into `E` by accumulating its binary digits. Every
+/// intermediate value is at most `offset`, so this does not overflow as long
+/// as `offset` itself is representable in `E`, which is the case for offsets
+/// within a set whose size can be computed.
+fn offset_to_integer<E: Integer + Copy>(offset: usize) -> E {
+    let mut result = E::zero();
+    for bit in (0..usize::BITS).rev() {
+        result = result + result;
+        if (offset >> bit) & 1 == 1 {
+            result = result + E::one();
+        }
+    }
+    result
 }
 
 pub struct IntegerSetIter<E: Integer + Copy + ToPrimitive> {
@@ -482,6 +980,39 @@ impl<E: Integer + Copy + ToPrimitive> Iterator for IntegerSetIter<E> {
     }
 }
 
+/// The borrowing counterpart of `IntegerSetIter`, see
+/// `OrderedIntegerSet::iter`.
+pub struct IntegerSetRefIter<'a, E: Integer + Copy + ToPrimitive> {
+    intervals: &'a [ContiguousIntegerSet<E>],
+    current_interval_index: usize,
+    current_element_index: E,
+}
+
+impl<'a, E: Integer + Copy + ToPrimitive> Iterator
+    for IntegerSetRefIter<'a, E>
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_interval_index >= self.intervals.len() {
+            None
+        } else {
+            let interval = &self.intervals[self.current_interval_index];
+            if self.current_element_index.to_usize().unwrap() >= interval.size()
+            {
+                self.current_interval_index += 1;
+                self.current_element_index = E::zero();
+                self.next()
+            } else {
+                let val = interval.get_start() + self.current_element_index;
+                self.current_element_index =
+                    self.current_element_index + E::one();
+                Some(val)
+            }
+        }
+    }
+}
+
 impl<E: Integer + Copy + ToPrimitive> ToIterator<'_, IntegerSetIter<E>, E>
     for OrderedIntegerSet<E>
 {
@@ -490,20 +1021,43 @@ impl<E: Integer + Copy + ToPrimitive> ToIterator<'_, IntegerSetIter<E>, E>
     }
 }
 
-impl<E> Sample<'_, IntegerSetIter<E>, E, OrderedIntegerSet<E>>
+impl<'a, E> Sample<'a, IntegerSetIter<E>, E, OrderedIntegerSet<E>>
     for OrderedIntegerSet<E>
 where
     E: Integer + Copy + Sum + ToPrimitive,
 {
+    /// Each drawn index is located by binary searching the cumulative sizes
+    /// of the intervals.
+    fn sample_with_replacement_with_rng<'s: 'a, R: Rng>(
+        &'s self,
+        size: usize,
+        rng: &mut R,
+    ) -> Result<OrderedIntegerSet<E>, String> {
+        let cumulative_sizes: Vec<usize> = self
+            .intervals
+            .iter()
+            .scan(0, |total, interval| {
+                *total += interval.size();
+                Some(*total)
+            })
+            .collect();
+        let population_size = cumulative_sizes.last().cloned().unwrap_or(0);
+        sample_with_replacement_by_index(population_size, size, rng, |i| {
+            let k = cumulative_sizes.partition_point(|&total| total <= i);
+            let preceding = if k == 0 { 0 } else { cumulative_sizes[k - 1] };
+            self.intervals[k].get_start() + offset_to_integer(i - preceding)
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use num::{integer::Integer, ToPrimitive};
+    use rand::{distributions::Uniform, rngs::StdRng, Rng, SeedableRng};
 
     use crate::{
         interval::traits::*,
-        set::traits::{Intersect, Refineable},
+        set::traits::{Finite, Intersect, IntersectionSize, Refineable},
         traits::{Collecting, ToIterator},
     };
 
@@ -521,6 +1075,421 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn test_ordered_integer_set_ref_iter() {
+        let set = OrderedIntegerSet::from_slice(&[[2, 4], [6, 7], [10, 10]]);
+        assert_eq!(
+            set.iter().collect::<Vec<i32>>(),
+            set.to_iter().collect::<Vec<i32>>()
+        );
+        assert_eq!(set.iter().collect::<Vec<i32>>(), vec![2, 3, 4, 6, 7, 10]);
+
+        // the iterator borrows the intervals of the set
+        let mut iter = set.iter();
+        assert!(std::ptr::eq(iter.intervals, &set.intervals[..]));
+        assert_eq!(iter.next(), Some(2));
+
+        let set = OrderedIntegerSet::from_slice(&[[-3, -1], [1, 2]]);
+        assert_eq!(set.iter().collect::<Vec<i64>>(), vec![-3, -2, -1, 1, 2]);
+        assert_eq!(set.iter().count(), set.size());
+
+        let empty = OrderedIntegerSet::<u8>::new();
+        assert_eq!(empty.iter().next(), None);
+    }
+
+    #[test]
+    fn test_map_intervals() {
+        let set = OrderedIntegerSet::from_slice(&[[1, 3], [6, 8], [12, 12]]);
+        assert_eq!(set.map_intervals(|i| i), set);
+
+        // negation reverses the order of the intervals
+        let negated = set.map_intervals(|i| {
+            let (start, end) = i.get_start_and_end();
+            ContiguousIntegerSet::new(-end, -start)
+        });
+        assert_eq!(
+            negated,
+            OrderedIntegerSet::from_slice(&[[-12, -12], [-8, -6], [-3, -1]])
+        );
+        assert_eq!(
+            negated.get_intervals_by_ref().first(),
+            Some(&ContiguousIntegerSet::new(-12, -12))
+        );
+
+        // scaling by -1/4 reverses the order and makes the intervals merge
+        let merged = set.map_intervals(|i| {
+            let (start, end) = i.get_start_and_end();
+            ContiguousIntegerSet::new(-end / 4, -start / 4)
+        });
+        assert_eq!(merged, OrderedIntegerSet::from_slice(&[[-3, 0]]));
+
+        // mapping onto the same interval collapses everything
+        assert_eq!(
+            set.map_intervals(|_| ContiguousIntegerSet::new(0, 1)),
+            OrderedIntegerSet::from_slice(&[[0, 1]])
+        );
+
+        // empty results are dropped
+        assert_eq!(
+            set.map_intervals(|i| {
+                let (start, end) = i.get_start_and_end();
+                ContiguousIntegerSet::new(start + 1, end - 1)
+            }),
+            OrderedIntegerSet::from_slice(&[[2, 2], [7, 7]])
+        );
+        assert_eq!(
+            OrderedIntegerSet::<i32>::new().map_intervals(|i| i),
+            OrderedIntegerSet::new()
+        );
+    }
+
+    #[test]
+    fn test_jaccard() {
+        fn test(a: &[[i32; 2]], b: &[[i32; 2]], expected: f64) {
+            let a = OrderedIntegerSet::from_slice(a);
+            let b = OrderedIntegerSet::from_slice(b);
+            assert_eq!(a.jaccard(&b), expected);
+            assert_eq!(b.jaccard(&a), expected);
+        }
+        // disjoint
+        test(&[[1, 3]], &[[5, 8]], 0.);
+        test(&[[1, 3], [10, 12]], &[[4, 9]], 0.);
+        test(&[[1, 3]], &[], 0.);
+        test(&[], &[], 0.);
+
+        // identical
+        test(&[[1, 3]], &[[1, 3]], 1.);
+        test(&[[1, 3], [6, 9]], &[[1, 3], [6, 9]], 1.);
+
+        // half of the union overlaps
+        test(&[[0, 9]], &[[0, 4]], 0.5);
+        test(&[[0, 5]], &[[2, 7]], 0.5);
+        test(&[[0, 3], [10, 13]], &[[0, 1], [10, 11], [20, 23]], 4. / 12.);
+    }
+
+    #[test]
+    fn test_coverage_ratio() {
+        fn test(intervals: &[[i32; 2]], bound: [i32; 2], expected: f64) {
+            assert_eq!(
+                OrderedIntegerSet::from_slice(intervals).coverage_ratio(
+                    &ContiguousIntegerSet::new(bound[0], bound[1])
+                ),
+                expected
+            );
+        }
+        // half
+        test(&[[0, 4]], [0, 9], 0.5);
+        test(&[[-5, 1], [4, 5], [8, 20]], [0, 7], 0.5);
+        // all
+        test(&[[0, 9]], [0, 9], 1.);
+        test(&[[-10, 3], [5, 30]], [6, 9], 1.);
+        // none
+        test(&[[0, 4]], [5, 9], 0.);
+        test(&[[0, 4], [10, 12]], [5, 9], 0.);
+        test(&[], [5, 9], 0.);
+        // empty bound
+        test(&[[0, 4]], [3, 2], 0.);
+    }
+
+    #[test]
+    fn test_split_at() {
+        fn test(index: usize, left: &[[i32; 2]], right: &[[i32; 2]]) {
+            let set = OrderedIntegerSet::from_slice(&[[0, 4], [10, 12]]);
+            let (l, r) = set.split_at(index);
+            assert_eq!(l, OrderedIntegerSet::from_slice(left));
+            assert_eq!(r, OrderedIntegerSet::from_slice(right));
+        }
+        test(3, &[[0, 2]], &[[3, 4], [10, 12]]);
+        test(0, &[], &[[0, 4], [10, 12]]);
+        test(5, &[[0, 4]], &[[10, 12]]);
+        test(6, &[[0, 4], [10, 10]], &[[11, 12]]);
+        test(8, &[[0, 4], [10, 12]], &[]);
+        test(20, &[[0, 4], [10, 12]], &[]);
+
+        let (l, r) = OrderedIntegerSet::<i32>::new().split_at(2);
+        assert!(l.is_empty());
+        assert!(r.is_empty());
+    }
+
+    #[test]
+    fn test_intersection_size() {
+        fn test(a: &[[i32; 2]], b: &[[i32; 2]]) {
+            let a = OrderedIntegerSet::from_slice(a);
+            let b = OrderedIntegerSet::from_slice(b);
+            let expected = a.intersect(&b).size();
+            assert_eq!(a.intersection_size(&b), expected);
+            assert_eq!(b.intersection_size(&a), expected);
+            for interval in b.intervals.iter() {
+                let expected = a.intersect(interval).size();
+                assert_eq!(a.intersection_size(interval), expected);
+                assert_eq!(interval.intersection_size(&a), expected);
+            }
+        }
+        test(&[[1, 3]], &[[5, 8]]);
+        test(&[[1, 3]], &[]);
+        test(&[[1, 3], [6, 9]], &[[1, 3], [6, 9]]);
+        test(&[[0, 5]], &[[2, 7]]);
+        test(&[[0, 3], [10, 13]], &[[0, 1], [3, 11], [20, 23]]);
+        test(&[[-10, -5], [-2, 4], [8, 9]], &[[-7, -1], [1, 8], [9, 30]]);
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let uniform = Uniform::new(-100, 100);
+        for _ in 0..20 {
+            let mut random_set = || {
+                OrderedIntegerSet::from_contiguous_integer_sets(
+                    (0..10)
+                        .map(|_| {
+                            let start = rng.sample(uniform);
+                            ContiguousIntegerSet::new(
+                                start,
+                                start + rng.sample(Uniform::new(0, 10)),
+                            )
+                        })
+                        .collect(),
+                )
+            };
+            let a = random_set();
+            let b = random_set();
+            assert_eq!(a.intersection_size(&b), a.intersect(&b).size());
+        }
+    }
+
+    #[test]
+    fn test_is_empty_set() {
+        assert!(OrderedIntegerSet::<i32>::new().is_empty_set());
+        assert!(!OrderedIntegerSet::from_slice(&[[2, 2]]).is_empty_set());
+        assert!(
+            !OrderedIntegerSet::from_slice(&[[1, 3], [6, 9]]).is_empty_set()
+        );
+
+        // empty intervals are not counted
+        let mut set = OrderedIntegerSet::from_slice(&[[5, 5]]);
+        set.remove(5);
+        assert!(set.is_empty_set());
+        assert_eq!(set.is_empty_set(), set.size() == 0);
+        let set =
+            OrderedIntegerSet::from_ordered_coalesced_contiguous_integer_sets(
+                vec![
+                    ContiguousIntegerSet::new(3, 2),
+                    ContiguousIntegerSet::new(8, 4),
+                ],
+            );
+        assert!(set.is_empty_set());
+        assert_eq!(set.is_empty_set(), set.size() == 0);
+
+        // the size of the set would overflow the element type
+        let set = OrderedIntegerSet::from_slice(&[[i64::MIN, i64::MAX]]);
+        assert!(!set.is_empty_set());
+    }
+
+    #[test]
+    fn test_bounding_interval() {
+        fn test(a: &[[i32; 2]], expected: Option<(i32, i32)>) {
+            assert_eq!(
+                OrderedIntegerSet::from_slice(a).bounding_interval(),
+                expected.map(|(s, e)| ContiguousIntegerSet::new(s, e))
+            );
+        }
+        test(&[[2, 5]], Some((2, 5)));
+        test(&[[2, 5], [7, 7]], Some((2, 7)));
+        test(&[[10, 12], [-3, 0], [5, 6]], Some((-3, 12)));
+        test(&[[4, 4]], Some((4, 4)));
+        test(&[], None);
+
+        let set = OrderedIntegerSet::from_slice(&[[0, 3], [8, 9], [20, 25]]);
+        let bound = set.bounding_interval().unwrap();
+        assert_eq!(set.intersect(&bound), set);
+        assert_eq!(bound.size(), 26);
+    }
+
+    #[test]
+    fn test_gaps() {
+        fn test(intervals: &[[i32; 2]], expected: &[[i32; 2]]) {
+            assert_eq!(
+                OrderedIntegerSet::from_slice(intervals).gaps(),
+                OrderedIntegerSet::from_slice(expected)
+            );
+        }
+        test(&[[1, 3], [7, 9]], &[[4, 6]]);
+        test(&[[-5, -3], [0, 0], [2, 4], [10, 12]], &[
+            [-2, -1],
+            [1, 1],
+            [5, 9],
+        ]);
+        test(&[[1, 3], [5, 5]], &[[4, 4]]);
+        test(&[[1, 3]], &[]);
+        test(&[[2, 2]], &[]);
+        test(&[], &[]);
+
+        // adjacent intervals are coalesced so there is no gap between them
+        test(&[[1, 3], [4, 6]], &[]);
+
+        let set =
+            OrderedIntegerSet::from_ordered_coalesced_contiguous_integer_sets(
+                vec![
+                    ContiguousIntegerSet::new(0usize, 2),
+                    ContiguousIntegerSet::new(4, 3),
+                    ContiguousIntegerSet::new(5, 6),
+                ],
+            );
+        assert_eq!(set.gaps(), OrderedIntegerSet::from_slice(&[[3, 4]]));
+        assert!(OrderedIntegerSet::<usize>::new().gaps().is_empty());
+    }
+
+    #[test]
+    fn test_from_contiguous_integer_sets_par() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let start_dist = Uniform::new(-1_000_000i64, 1_000_000);
+        let length_dist = Uniform::new(-5i64, 40);
+        let intervals: Vec<ContiguousIntegerSet<i64>> = (0..200_000)
+            .map(|_| {
+                let start = rng.sample(start_dist);
+                ContiguousIntegerSet::new(
+                    start,
+                    start + rng.sample(length_dist),
+                )
+            })
+            .collect();
+        let expected =
+            OrderedIntegerSet::from_contiguous_integer_sets(intervals.clone());
+        assert!(expected.num_intervals() > 1000);
+        assert_eq!(
+            OrderedIntegerSet::from_contiguous_integer_sets_par(intervals),
+            expected
+        );
+
+        // a single interval spanning all the chunks
+        let mut intervals: Vec<ContiguousIntegerSet<i64>> = (0..50_000)
+            .map(|i| ContiguousIntegerSet::new(i * 3, i * 3 + 1))
+            .collect();
+        intervals.push(ContiguousIntegerSet::new(-1, 200_000));
+        assert_eq!(
+            OrderedIntegerSet::from_contiguous_integer_sets_par(intervals),
+            OrderedIntegerSet::from_slice(&[[-1, 200_000]])
+        );
+
+        assert_eq!(
+            OrderedIntegerSet::from_contiguous_integer_sets_par(vec![
+                ContiguousIntegerSet::new(3, 1),
+                ContiguousIntegerSet::new(5, 6)
+            ]),
+            OrderedIntegerSet::from_slice(&[[5, 6]])
+        );
+        assert_eq!(
+            OrderedIntegerSet::<usize>::from_contiguous_integer_sets_par(
+                vec![]
+            ),
+            OrderedIntegerSet::new()
+        );
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut set = OrderedIntegerSet::from_slice(&[[0, 9]]);
+        set.retain(|x| x % 2 == 0);
+        assert_eq!(
+            set,
+            OrderedIntegerSet::from_slice(&[[0, 0], [2, 2], [4, 4], [6, 6], [
+                8, 8
+            ]])
+        );
+        assert_eq!(set.num_intervals(), 5);
+        assert_eq!(set.size(), 5);
+
+        let mut set = OrderedIntegerSet::from_slice(&[[0, 4], [6, 9]]);
+        set.retain(|x| x != 6);
+        assert_eq!(set, OrderedIntegerSet::from_slice(&[[0, 4], [7, 9]]));
+        set.retain(|x| x >= 2);
+        assert_eq!(set, OrderedIntegerSet::from_slice(&[[2, 4], [7, 9]]));
+        set.retain(|_| true);
+        assert_eq!(set, OrderedIntegerSet::from_slice(&[[2, 4], [7, 9]]));
+        set.retain(|_| false);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = OrderedIntegerSet::from_slice(&[[1, 5]]);
+        assert!(set.remove(3));
+        assert_eq!(set, OrderedIntegerSet::from_slice(&[[1, 2], [4, 5]]));
+
+        let mut set =
+            OrderedIntegerSet::from_slice(&[[1usize, 5], [8, 8], [10, 12]]);
+        // at the start
+        assert!(set.remove(1));
+        assert_eq!(
+            set,
+            OrderedIntegerSet::from_slice(&[[2, 5], [8, 8], [10, 12]])
+        );
+        // at the end
+        assert!(set.remove(12));
+        assert_eq!(
+            set,
+            OrderedIntegerSet::from_slice(&[[2, 5], [8, 8], [10, 11]])
+        );
+        // a single-element interval
+        assert!(set.remove(8));
+        assert_eq!(set, OrderedIntegerSet::from_slice(&[[2, 5], [10, 11]]));
+        // in the middle
+        assert!(set.remove(4));
+        assert_eq!(
+            set,
+            OrderedIntegerSet::from_slice(&[[2, 3], [5, 5], [10, 11]])
+        );
+        // absent elements
+        for &x in [0, 1, 4, 6, 8, 9, 12, 100].iter() {
+            assert!(!set.remove(x));
+        }
+        assert_eq!(
+            set,
+            OrderedIntegerSet::from_slice(&[[2, 3], [5, 5], [10, 11]])
+        );
+        for &x in [2, 3, 5, 10, 11].iter() {
+            assert!(set.remove(x));
+        }
+        assert!(set.is_empty());
+        assert_eq!(set.num_intervals(), 0);
+        assert!(!set.remove(2));
+    }
+
+    #[test]
+    fn test_count_matching() {
+        let set = OrderedIntegerSet::from_slice(&[[-3, 4], [10, 15], [20, 20]]);
+        assert_eq!(set.count_matching(|x| x % 2 == 0), 8);
+        assert_eq!(set.count_matching(|&x| x < 0), 3);
+        assert_eq!(set.count_matching(|&x| x > 12), 4);
+        assert_eq!(set.count_matching(|_| true), 15);
+        assert_eq!(OrderedIntegerSet::<i32>::new().count_matching(|_| true), 0);
+    }
+
+    #[test]
+    fn test_integer_set_collect_all() {
+        let mut set = OrderedIntegerSet::from_slice(&[[3, 5], [10, 12]]);
+        set.collect_all(vec![20, 6, 1, 8, 9, 2, 2, 21, 11, 0, 30]);
+        assert_eq!(
+            set,
+            OrderedIntegerSet::from_slice(&[[0, 6], [8, 12], [20, 21], [
+                30, 30
+            ]])
+        );
+
+        let mut set = OrderedIntegerSet::new();
+        set.collect_all(Vec::new());
+        assert_eq!(set, OrderedIntegerSet::<usize>::new());
+        set.collect_all(vec![4usize, 2, 3, 7]);
+        assert_eq!(set, OrderedIntegerSet::from_slice(&[[2, 4], [7, 7]]));
+
+        // agrees with collecting one by one
+        let items = vec![-3, 15, 4, -2, 5, 6, 14, 0, 6, 20, -10, 19];
+        let mut expected = OrderedIntegerSet::from_slice(&[[1, 2], [8, 9]]);
+        for &item in items.iter() {
+            expected.collect(item);
+        }
+        let mut set = OrderedIntegerSet::from_slice(&[[1, 2], [8, 9]]);
+        set.collect_all(items);
+        assert_eq!(set, expected);
+    }
+
     #[test]
     fn test_integer_set_collect() {
         let mut set = OrderedIntegerSet::new();
@@ -556,6 +1525,56 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_insert_interval() {
+        fn test(a: &[[i32; 2]], b: [i32; 2], expected: &[[i32; 2]]) {
+            let mut set = OrderedIntegerSet::from_slice(a);
+            set.insert_interval(ContiguousIntegerSet::new(b[0], b[1]));
+            assert_eq!(set, OrderedIntegerSet::from_slice(expected));
+        }
+        // bridging two existing runs
+        test(&[[1, 3], [6, 8]], [4, 5], &[[1, 8]]);
+        test(&[[1, 3], [6, 8]], [2, 7], &[[1, 8]]);
+        test(&[[1, 3], [6, 8], [10, 12]], [3, 10], &[[1, 12]]);
+        test(&[[1, 3], [6, 8], [10, 12], [20, 21]], [0, 9], &[[0, 12], [
+            20, 21,
+        ]]);
+        test(&[[-5, -3], [1, 3], [6, 8], [20, 21]], [4, 5], &[
+            [-5, -3],
+            [1, 8],
+            [20, 21],
+        ]);
+        // coalescing with a single run
+        test(&[[1, 3], [6, 8]], [4, 4], &[[1, 4], [6, 8]]);
+        test(&[[1, 3], [6, 8]], [9, 10], &[[1, 3], [6, 10]]);
+        test(&[[1, 3], [6, 8]], [7, 7], &[[1, 3], [6, 8]]);
+        // no coalescing
+        test(&[[1, 3], [10, 12]], [5, 8], &[[1, 3], [5, 8], [10, 12]]);
+        test(&[[1, 3]], [-5, -1], &[[-5, -1], [1, 3]]);
+        test(&[[1, 3]], [6, 6], &[[1, 3], [6, 6]]);
+        test(&[], [2, 3], &[[2, 3]]);
+        // empty interval
+        test(&[[1, 3]], [6, 5], &[[1, 3]]);
+
+        // does not overflow at the extremes of the type
+        let mut set = OrderedIntegerSet::from_slice(&[[240u8, 248]]);
+        set.insert_interval(ContiguousIntegerSet::new(250, 255));
+        assert_eq!(
+            set,
+            OrderedIntegerSet::from_slice(&[[240, 248], [250, 255]])
+        );
+        set.insert_interval(ContiguousIntegerSet::new(249, 249));
+        assert_eq!(set, OrderedIntegerSet::from_slice(&[[240, 255]]));
+        set.insert_interval(ContiguousIntegerSet::new(0, 239));
+        assert_eq!(set, OrderedIntegerSet::from_slice(&[[0, 255]]));
+        let mut set = OrderedIntegerSet::from_slice(&[[-128i8, -128]]);
+        set.insert_interval(ContiguousIntegerSet::new(127, 127));
+        assert_eq!(
+            set,
+            OrderedIntegerSet::from_slice(&[[-128, -128], [127, 127]])
+        );
+    }
+
     #[test]
     fn test_coalesce_with() {
         fn test<E: Copy + Integer + std::fmt::Debug>(
@@ -578,6 +1597,22 @@ mod tests {
         test(-3, -1, -1, 2, Some(ContiguousIntegerSet::new(-3, 2)));
         test(3, 5, 7, 9, None);
         test(9, 5, 5, 7, Some(ContiguousIntegerSet::new(5, 7)));
+        test(
+            250u8,
+            254,
+            255,
+            255,
+            Some(ContiguousIntegerSet::new(250, 255)),
+        );
+        test(0u8, 0, 255, 255, None);
+        test(-128i8, -128, 127, 127, None);
+        test(
+            -128i8,
+            0,
+            1,
+            127,
+            Some(ContiguousIntegerSet::new(-128, 127)),
+        );
     }
 
     #[test]
@@ -751,4 +1786,33 @@ mod tests {
         test(&[-2i32, 4], &[0, 3], &[[-2, -1], [0, 3], [4, 4]]);
         test(&[-2i32, 4], &[0, 3], &[[-2, -1], [0, 3], [4, 4]]);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let set =
+            OrderedIntegerSet::from_slice(&[[1usize, 3], [6, 9], [12, 12]]);
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"start":1,"end":3},{"start":6,"end":9},{"start":12,"end":12}]"#
+        );
+        assert_eq!(
+            serde_json::from_str::<OrderedIntegerSet<usize>>(&json).unwrap(),
+            set
+        );
+
+        // unsorted, overlapping and empty intervals are coalesced
+        let json = r#"[
+            {"start":6,"end":8},
+            {"start":12,"end":12},
+            {"start":5,"end":4},
+            {"start":1,"end":3},
+            {"start":7,"end":9}
+        ]"#;
+        assert_eq!(
+            serde_json::from_str::<OrderedIntegerSet<usize>>(json).unwrap(),
+            set
+        );
+    }
 }
@@ -1,12 +1,15 @@
 use crate::{
-    interval::traits::{CoalesceIntervals, Interval},
+    interval::traits::{Coalesce, CoalesceIntervals, Interval},
     search::binary_search::BinarySearch,
     set::{
         contiguous_integer_set::ContiguousIntegerSet,
-        ordered_integer_set::OrderedIntegerSet, traits::Set,
+        ordered_integer_set::OrderedIntegerSet,
+        traits::{Set, SetOps},
     },
 };
-use num::{integer::Integer, traits::cast::ToPrimitive};
+use num::{
+    integer::Integer, traits::cast::ToPrimitive, CheckedAdd, FromPrimitive,
+};
 use std::{
     cmp::{max, min, Ordering},
     ops::{Sub, SubAssign},
@@ -50,6 +53,96 @@ impl<E: Integer + Copy + ToPrimitive> Sub for ContiguousIntegerSet<E> {
     }
 }
 
+impl<E: Integer + Copy + ToPrimitive> ContiguousIntegerSet<E> {
+    /// Returns the union of the two sets as an `OrderedIntegerSet`, which
+    /// consists of a single interval if the two sets are coalesceable and of
+    /// both intervals otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::{
+    ///     contiguous_integer_set::ContiguousIntegerSet,
+    ///     ordered_integer_set::OrderedIntegerSet,
+    /// };
+    ///
+    /// let a = ContiguousIntegerSet::new(2, 5);
+    /// assert_eq!(
+    ///     a.union(&ContiguousIntegerSet::new(6, 8)),
+    ///     OrderedIntegerSet::from_slice(&[[2, 8]])
+    /// );
+    /// assert_eq!(
+    ///     a.union(&ContiguousIntegerSet::new(10, 12)),
+    ///     OrderedIntegerSet::from_slice(&[[2, 5], [10, 12]])
+    /// );
+    /// ```
+    pub fn union(
+        &self,
+        other: &ContiguousIntegerSet<E>,
+    ) -> OrderedIntegerSet<E> {
+        let intervals = if self.is_empty() && other.is_empty() {
+            vec![]
+        } else {
+            match self.coalesce_with(other) {
+                Some(coalesced) => vec![coalesced],
+                None => vec![*min(self, other), *max(self, other)],
+            }
+        };
+        OrderedIntegerSet::from_ordered_coalesced_contiguous_integer_sets(
+            intervals,
+        )
+    }
+
+    /// Returns every `step`-th element of the set starting from the `start`,
+    /// i.e. `{start, start + step, start + 2 * step, ...}` up to the `end`, as
+    /// an `OrderedIntegerSet` of singleton intervals. A `step` of 1 returns the
+    /// set itself.
+    ///
+    /// # Panics
+    /// Panics if `step` is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use math::set::{
+    ///     contiguous_integer_set::ContiguousIntegerSet,
+    ///     ordered_integer_set::OrderedIntegerSet,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     ContiguousIntegerSet::new(0, 10).stride(3),
+    ///     OrderedIntegerSet::from_slice(&[[0, 0], [3, 3], [6, 6], [9, 9]])
+    /// );
+    /// ```
+    pub fn stride(&self, step: usize) -> OrderedIntegerSet<E>
+    where
+        E: CheckedAdd + FromPrimitive, {
+        assert!(step > 0, "step must be positive");
+        if self.is_empty() {
+            return OrderedIntegerSet::new();
+        }
+        if step == 1 {
+            return OrderedIntegerSet::from_contiguous_integer_sets(vec![
+                *self,
+            ]);
+        }
+        let (start, end) = self.get_start_and_end();
+        let mut intervals = vec![ContiguousIntegerSet::new(start, start)];
+        // a step that does not fit in E is larger than the whole set
+        if let Some(step) = E::from_usize(step) {
+            let mut x = start;
+            loop {
+                match x.checked_add(&step) {
+                    Some(next) if next <= end => x = next,
+                    _ => break,
+                }
+                intervals.push(ContiguousIntegerSet::new(x, x));
+            }
+        }
+        OrderedIntegerSet::from_ordered_coalesced_contiguous_integer_sets(
+            intervals,
+        )
+    }
+}
+
 impl<E: Integer + Copy + ToPrimitive> Sub<&ContiguousIntegerSet<E>>
     for OrderedIntegerSet<E>
 {
@@ -162,7 +255,25 @@ impl<E: Integer + Copy + ToPrimitive> Sub<&OrderedIntegerSet<E>>
 {
     type Output = Self;
 
+    #[inline]
     fn sub(self, rhs: &OrderedIntegerSet<E>) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+/// # Example
+/// ```
+/// use math::set::{ordered_integer_set::OrderedIntegerSet, traits::SetOps};
+///
+/// let a = OrderedIntegerSet::from_slice(&[[1, 6], [9, 12]]);
+/// let b = OrderedIntegerSet::from_slice(&[[3, 4], [11, 15]]);
+/// assert_eq!(
+///     a.difference(&b),
+///     OrderedIntegerSet::from_slice(&[[1, 2], [5, 6], [9, 10]])
+/// );
+/// ```
+impl<E: Integer + Copy + ToPrimitive> SetOps for OrderedIntegerSet<E> {
+    fn difference(&self, rhs: &OrderedIntegerSet<E>) -> Self {
         let mut diff = Vec::new();
         let mut rhs_i = 0;
         let num_rhs_intervals = rhs.intervals.len();
@@ -261,6 +372,77 @@ mod tests {
         test!(2, 5, 0, 8, &[]);
     }
 
+    #[test]
+    fn test_contiguous_union() {
+        fn test(a: [i32; 2], b: [i32; 2], expected: &[[i32; 2]]) {
+            let s1 = ContiguousIntegerSet::new(a[0], a[1]);
+            let s2 = ContiguousIntegerSet::new(b[0], b[1]);
+            let expected = OrderedIntegerSet::from_slice(expected);
+            assert_eq!(s1.union(&s2), expected);
+            assert_eq!(s2.union(&s1), expected);
+        }
+        // mergeable
+        test([2, 5], [6, 8], &[[2, 8]]);
+        test([2, 5], [4, 8], &[[2, 8]]);
+        test([2, 10], [4, 8], &[[2, 10]]);
+        test([2, 5], [2, 5], &[[2, 5]]);
+        test([-3, -1], [0, 0], &[[-3, 0]]);
+        // disjoint
+        test([2, 5], [7, 8], &[[2, 5], [7, 8]]);
+        test([-10, -8], [8, 10], &[[-10, -8], [8, 10]]);
+        // empty
+        test([2, 5], [8, 7], &[[2, 5]]);
+        test([5, 2], [8, 7], &[]);
+    }
+
+    #[test]
+    fn test_contiguous_stride() {
+        fn test(a: [i32; 2], step: usize, expected: &[[i32; 2]]) {
+            assert_eq!(
+                ContiguousIntegerSet::new(a[0], a[1]).stride(step),
+                OrderedIntegerSet::from_slice(expected)
+            );
+        }
+        test([0, 10], 3, &[[0, 0], [3, 3], [6, 6], [9, 9]]);
+        test([0, 9], 3, &[[0, 0], [3, 3], [6, 6], [9, 9]]);
+        test([0, 10], 5, &[[0, 0], [5, 5], [10, 10]]);
+        test([-7, 2], 4, &[[-7, -7], [-3, -3], [1, 1]]);
+        test([0, 10], 11, &[[0, 0]]);
+        test([0, 10], 1, &[[0, 10]]);
+        test([4, 4], 2, &[[4, 4]]);
+        test([5, 4], 2, &[]);
+
+        assert_eq!(
+            ContiguousIntegerSet::new(250u8, 255).stride(300),
+            OrderedIntegerSet::from_slice(&[[250, 250]])
+        );
+        assert_eq!(
+            ContiguousIntegerSet::new(250u8, 255).stride(2),
+            OrderedIntegerSet::from_slice(&[[250, 250], [252, 252], [
+                254, 254
+            ]])
+        );
+        assert_eq!(
+            ContiguousIntegerSet::new(-100i8, 100).stride(3),
+            OrderedIntegerSet::from_contiguous_integer_sets(
+                (-100i8..=100)
+                    .step_by(3)
+                    .map(|x| ContiguousIntegerSet::new(x, x))
+                    .collect()
+            )
+        );
+        assert_eq!(
+            ContiguousIntegerSet::new(-128i8, 127).stride(1),
+            OrderedIntegerSet::from_slice(&[[-128, 127]])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be positive")]
+    fn test_contiguous_stride_zero_step() {
+        ContiguousIntegerSet::new(0, 10).stride(0);
+    }
+
     #[test]
     fn test_ordered_sub_contiguous() {
         macro_rules! test {
@@ -1,6 +1,6 @@
 use std::{collections::HashSet, hash::Hash};
 
-use crate::set::traits::{Finite, Intersect, Set};
+use crate::set::traits::{Finite, Intersect, Set, SetOps};
 
 impl<T> Finite for Vec<T> {
     fn size(&self) -> usize {
@@ -34,11 +34,26 @@ impl<T: Clone + Eq + Hash> Intersect<&HashSet<T>, HashSet<T>> for HashSet<T> {
     }
 }
 
+/// Note that the inherent `HashSet::difference` returning an iterator takes
+/// precedence in method call syntax on a concrete `HashSet`.
+impl<T: Clone + Eq + Hash> SetOps for HashSet<T> {
+    fn difference(&self, other: &HashSet<T>) -> HashSet<T> {
+        HashSet::difference(self, other).cloned().collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::set::traits::{Intersect, Set};
+    use crate::set::{
+        ordered_integer_set::OrderedIntegerSet,
+        traits::{Finite, Intersect, Set, SetOps},
+    };
     use std::collections::HashSet;
 
+    fn generic_difference<S: SetOps>(a: &S, b: &S) -> S {
+        a.difference(b)
+    }
+
     #[test]
     fn test_set() {
         let mut s = HashSet::new();
@@ -50,6 +65,42 @@ mod tests {
         assert_eq!(Set::<i32>::contains(&s, &2), false);
     }
 
+    #[test]
+    fn test_is_empty_set() {
+        let mut s = HashSet::new();
+        assert!(s.is_empty_set());
+        s.insert(3);
+        assert!(!s.is_empty_set());
+        assert!(Vec::<i32>::new().is_empty_set());
+        assert!(!vec![1].is_empty_set());
+    }
+
+    #[test]
+    fn test_set_ops_difference() {
+        let s1: HashSet<i32> = [1, 2, 3, 4].iter().cloned().collect();
+        let s2: HashSet<i32> = [2, 3, 7].iter().cloned().collect();
+        let expected: HashSet<i32> = [1, 4].iter().cloned().collect();
+        assert_eq!(generic_difference(&s1, &s2), expected);
+        assert_eq!(SetOps::difference(&s1, &s2), expected);
+        assert_eq!(generic_difference(&s2, &s1), [7].iter().cloned().collect());
+        assert_eq!(generic_difference(&s1, &s1), HashSet::new());
+        assert_eq!(generic_difference(&s1, &HashSet::new()), s1);
+
+        let a = OrderedIntegerSet::from_slice(&[[1, 6], [9, 12]]);
+        let b = OrderedIntegerSet::from_slice(&[[3, 4], [11, 15]]);
+        assert_eq!(
+            generic_difference(&a, &b),
+            OrderedIntegerSet::from_slice(&[[1, 2], [5, 6], [9, 10]])
+        );
+        assert_eq!(generic_difference(&a, &b), a.clone() - &b);
+        assert_eq!(
+            generic_difference(&b, &a),
+            OrderedIntegerSet::from_slice(&[[13, 15]])
+        );
+        assert!(generic_difference(&a, &a).is_empty());
+        assert_eq!(generic_difference(&a, &OrderedIntegerSet::new()), a);
+    }
+
     #[test]
     fn test_hashset_intersect() {
         let s1: HashSet<i32> = [1, 2, 3, 4].iter().cloned().collect();
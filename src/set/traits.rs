@@ -10,6 +10,19 @@ pub trait Intersect<S, O> {
     fn has_non_empty_intersection_with(&self, other: S) -> bool;
 }
 
+/// Set operations producing a set of the same type, so that generic code can
+/// operate on any set type.
+pub trait SetOps: Sized {
+    /// Returns the elements in `self` that are not in `other`.
+    fn difference(&self, other: &Self) -> Self;
+}
+
+/// The size of the intersection computed without constructing the
+/// intersection itself.
+pub trait IntersectionSize<S> {
+    fn intersection_size(&self, other: S) -> usize;
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum CountableType {
     Finite(usize),
@@ -32,6 +45,12 @@ pub trait Countable {
 
 pub trait Finite {
     fn size(&self) -> usize;
+
+    /// Implementors should override this if emptiness can be determined
+    /// without computing the size.
+    fn is_empty_set(&self) -> bool {
+        self.size() == 0
+    }
 }
 
 /// Given two sets of the same type that are `Refineable`, their common
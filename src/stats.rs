@@ -2,20 +2,63 @@
 
 pub mod correlation;
 
-use num::{traits::ToPrimitive, Float};
+use num::{traits::ToPrimitive, Float, Integer};
 use std::{
     cmp::{min, Ordering},
-    ops::Deref,
+    collections::HashMap,
+    convert::TryFrom,
+    hash::Hash,
+    ops::{Deref, Sub},
 };
 
+/// # Panics
+/// Panics if `C(n, 2)` does not fit in `usize`.
 pub fn n_choose_2(n: usize) -> usize {
-    if n < 2 {
-        0
-    } else {
-        n * (n - 1) / 2
+    usize::try_from(n_choose_k(n, 2))
+        .unwrap_or_else(|_| panic!("n_choose_2({}) overflows usize", n))
+}
+
+/// The binomial coefficient `C(n, k)`, which is `0` if `k > n`.
+///
+/// The coefficient is built up multiplicatively as
+/// `C(n, i + 1) = C(n, i) * (n - i) / (i + 1)` with the common factors
+/// cancelled before multiplying, so that no intermediate value exceeds the
+/// final result.
+///
+/// # Panics
+/// Panics if the result does not fit in a `u128`.
+///
+/// # Example
+/// ```
+/// use math::stats::n_choose_k;
+///
+/// assert_eq!(n_choose_k(10, 3), 120);
+/// assert_eq!(n_choose_k(10, 0), 1);
+/// assert_eq!(n_choose_k(3, 4), 0);
+/// ```
+pub fn n_choose_k(n: usize, k: usize) -> u128 {
+    if k > n {
+        return 0;
     }
+    let k = min(k, n - k) as u128;
+    let n = n as u128;
+    let mut result = 1u128;
+    for i in 0..k {
+        let divisor = i + 1;
+        let g = result.gcd(&divisor);
+        result = (result / g)
+            .checked_mul((n - i) / (divisor / g))
+            .unwrap_or_else(|| {
+                panic!("n_choose_k({}, {}) overflows u128", n, k)
+            });
+    }
+    result
 }
 
+/// Compensated summation of `op(e)` over the elements `e` of
+/// `element_iterator`, accumulated at the precision of the `Float` type
+/// `Dtype`, e.g. `f32` or `f64`. This is the sum returned by
+/// `kahan_sigma_return_counter` without the element count.
 pub fn kahan_sigma<E, I: Iterator<Item = E>, F, Dtype>(
     element_iterator: I,
     op: F,
@@ -23,16 +66,7 @@ pub fn kahan_sigma<E, I: Iterator<Item = E>, F, Dtype>(
 where
     F: Fn(E) -> Dtype,
     Dtype: Float, {
-    // Kahan summation algorithm
-    let mut sum = Dtype::zero();
-    let mut lower_bits = Dtype::zero();
-    for a in element_iterator {
-        let y = op(a) - lower_bits;
-        let new_sum = sum + y;
-        lower_bits = (new_sum - sum) - y;
-        sum = new_sum;
-    }
-    sum
+    kahan_sigma_return_counter(element_iterator, op).0
 }
 
 pub fn kahan_sigma_return_counter<E, I: Iterator<Item = E>, F, Dtype>(
@@ -156,6 +190,52 @@ where
     variance(element_iterator, ddof).sqrt()
 }
 
+/// Returns the `(min, max)` of the elements found in a single pass, or `None`
+/// if there are no elements. Elements that are incomparable to the current
+/// extrema, e.g. `NaN`, do not replace them.
+///
+/// # Example
+/// ```
+/// use math::stats::min_max;
+///
+/// assert_eq!(min_max([3, -2, 7, 0].iter()), Some((-2, 7)));
+/// assert_eq!(min_max(Vec::<f64>::new().iter()), None);
+/// ```
+pub fn min_max<'a, A, I: Iterator<Item = &'a A>>(
+    mut element_iterator: I,
+) -> Option<(A, A)>
+where
+    A: Copy + PartialOrd + 'a, {
+    let first = *element_iterator.next()?;
+    Some(element_iterator.fold((first, first), |(min, max), &a| {
+        if a < min {
+            (a, max)
+        } else if a > max {
+            (min, a)
+        } else {
+            (min, max)
+        }
+    }))
+}
+
+/// Returns `max - min` of the elements computed with `min_max`, or `None` if
+/// there are no elements.
+///
+/// # Example
+/// ```
+/// use math::stats::range;
+///
+/// assert_eq!(range([3, -2, 7, 0].iter()), Some(9));
+/// assert_eq!(range([1.5].iter()), Some(0.));
+/// ```
+pub fn range<'a, A, I: Iterator<Item = &'a A>>(
+    element_iterator: I,
+) -> Option<A>
+where
+    A: Copy + PartialOrd + Sub<Output = A> + 'a, {
+    min_max(element_iterator).map(|(min, max)| max - min)
+}
+
 /// `percentile_ratio` is `percentile / 100`,
 /// e.g. the 90-th percentile corresponds to a `percentile_ratio` of `0.9`.
 pub fn percentile_by<T, F>(
@@ -171,11 +251,138 @@ where
     }
     numbers.sort_by(|a, b| compare(a, b));
 
-    Ok(numbers[min(
-        (numbers.len() as f64 * percentile_ratio).floor() as usize,
-        numbers.len() - 1,
-    )]
-    .clone())
+    Ok(numbers[percentile_index(numbers.len(), percentile_ratio)].clone())
+}
+
+/// Same as `percentile_by` except that the values at all the `ratios` are
+/// computed with a single sort, and are returned in the order of `ratios`.
+///
+/// # Example
+/// ```
+/// use math::stats::percentiles_by;
+///
+/// let numbers = vec![7, 3, 1, 9, 5, 2, 8, 4, 6, 0];
+/// assert_eq!(
+///     percentiles_by(numbers, &[0.25, 0.5, 0.75], |a, b| a.cmp(b)),
+///     Ok(vec![2, 5, 7])
+/// );
+/// ```
+pub fn percentiles_by<T, F>(
+    mut numbers: Vec<T>,
+    ratios: &[f64],
+    mut compare: F,
+) -> Result<Vec<T>, String>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering, {
+    if numbers.is_empty() {
+        return Err("percentiles_by received an empty vector".to_string());
+    }
+    if let Some(r) = ratios.iter().find(|&&r| !(0. ..=1.).contains(&r)) {
+        return Err(format!("percentile ratio {} is not in [0, 1]", r));
+    }
+    numbers.sort_by(|a, b| compare(a, b));
+
+    Ok(ratios
+        .iter()
+        .map(|&r| numbers[percentile_index(numbers.len(), r)].clone())
+        .collect())
+}
+
+/// Returns the most frequent value in `values`, or `None` if `values` is
+/// empty. Ties are broken in favor of the value encountered first.
+///
+/// # Example
+/// ```
+/// use math::stats::mode;
+///
+/// assert_eq!(mode(&[3, 1, 3, 2, 1, 3]), Some(3));
+/// assert_eq!(mode(&["b", "a", "a", "b"]), Some("b"));
+/// assert_eq!(mode::<i32>(&[]), None);
+/// ```
+pub fn mode<T: Eq + Hash + Clone>(values: &[T]) -> Option<T> {
+    let mut counts: HashMap<&T, usize> = HashMap::new();
+    for v in values.iter() {
+        *counts.entry(v).or_insert(0) += 1;
+    }
+    let mut best: Option<(&T, usize)> = None;
+    for v in values.iter() {
+        let count = counts[v];
+        match best {
+            Some((_, best_count)) if best_count >= count => {}
+            _ => best = Some((v, count)),
+        }
+    }
+    best.map(|(v, _)| v.clone())
+}
+
+/// Sorts the `numbers`, discards the lowest and the highest
+/// `floor(numbers.len() * trim_ratio)` of them, and returns the mean of the
+/// rest. Returns an error if `numbers` is empty or contains `NaN`, or if
+/// `trim_ratio` is not in `[0, 0.5)`.
+///
+/// # Example
+/// ```
+/// use math::stats::trimmed_mean;
+///
+/// let numbers = [3., -100., 1., 2., 500.];
+/// assert_eq!(trimmed_mean(&numbers, 0.2), Ok(2.));
+/// assert!(trimmed_mean(&numbers, 0.5).is_err());
+/// ```
+pub fn trimmed_mean(numbers: &[f64], trim_ratio: f64) -> Result<f64, String> {
+    if numbers.is_empty() {
+        return Err("trimmed_mean received an empty slice".to_string());
+    }
+    if !(0. ..0.5).contains(&trim_ratio) {
+        return Err(format!("trim_ratio {} is not in [0, 0.5)", trim_ratio));
+    }
+    if numbers.iter().any(|a| a.is_nan()) {
+        return Err("trimmed_mean received NaN".to_string());
+    }
+    let mut sorted = numbers.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let num_trimmed = (sorted.len() as f64 * trim_ratio).floor() as usize;
+    Ok(mean(sorted[num_trimmed..sorted.len() - num_trimmed].iter()))
+}
+
+/// Returns the autocorrelation of the `series` at the lags `0..=max_lag`,
+/// where the autocorrelation at lag `k` is
+/// `sum_t (x_t - m) * (x_{t+k} - m) / sum_t (x_t - m)^2` with `m` being the
+/// mean of the `series`. The sums are computed with Kahan summation.
+///
+/// The autocorrelation at lag 0 is 1, and is 0 for lags beyond the length of
+/// the `series`. All the values are `NaN` if the `series` is empty or
+/// constant.
+///
+/// # Example
+/// ```
+/// use math::stats::autocorrelation;
+///
+/// let acf = autocorrelation(&[1., -1., 1., -1.], 2);
+/// assert_eq!(acf, vec![1., -0.75, 0.5]);
+/// ```
+pub fn autocorrelation(series: &[f64], max_lag: usize) -> Vec<f64> {
+    let m = mean(series.iter());
+    let deviations: Vec<f64> = series.iter().map(|&x| x - m).collect();
+    let n = deviations.len();
+    let denominator: f64 = kahan_sigma(deviations.iter(), |&d| d * d);
+    (0..=max_lag)
+        .map(|k| {
+            let numerator: f64 = if k < n {
+                kahan_sigma(0..n - k, |t| deviations[t] * deviations[t + k])
+            } else {
+                0.
+            };
+            numerator / denominator
+        })
+        .collect()
+}
+
+/// The nearest-rank index of the `percentile_ratio` in a sorted sequence of
+/// length `len`.
+#[inline]
+fn percentile_index(len: usize, percentile_ratio: f64) -> usize {
+    min((len as f64 * percentile_ratio).floor() as usize, len - 1)
 }
 
 #[cfg(test)]
@@ -185,7 +392,10 @@ mod tests {
     use rand::{seq::SliceRandom, Rng};
 
     use super::{
-        mean, percentile_by, standard_deviation, sum, sum_of_squares, variance,
+        autocorrelation, kahan_sigma, kahan_sigma_return_counter, mean,
+        min_max, mode, n_choose_2, n_choose_k, percentile_by, percentiles_by,
+        range, standard_deviation, sum, sum_of_squares, sum_of_squares_f32,
+        trimmed_mean, variance,
     };
     use crate::stats::sum_f32;
 
@@ -203,6 +413,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_n_choose_k() {
+        assert_eq!(n_choose_k(10, 3), 120);
+        assert_eq!(n_choose_k(10, 7), 120);
+        assert_eq!(n_choose_k(52, 5), 2598960);
+        assert_eq!(n_choose_k(0, 0), 1);
+        assert_eq!(n_choose_k(5, 0), 1);
+        assert_eq!(n_choose_k(5, 5), 1);
+        assert_eq!(n_choose_k(5, 6), 0);
+        assert_eq!(n_choose_k(0, 1), 0);
+        assert_eq!(n_choose_k(100, 50), 100891344545564193334812497256);
+        assert_eq!(n_choose_k(130, 65), 95067625827960698145584333020095113100);
+
+        // Pascal's rule
+        for n in 1..60 {
+            for k in 1..=n {
+                assert_eq!(
+                    n_choose_k(n, k),
+                    n_choose_k(n - 1, k - 1) + n_choose_k(n - 1, k)
+                );
+            }
+        }
+
+        for n in 0..100 {
+            assert_eq!(n_choose_2(n) as u128, n_choose_k(n, 2));
+        }
+        assert_eq!(n_choose_2(0), 0);
+        assert_eq!(n_choose_2(1), 0);
+        assert_eq!(n_choose_2(4), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_n_choose_k_overflow() {
+        n_choose_k(200, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows usize")]
+    fn test_n_choose_2_overflow() {
+        n_choose_2(usize::MAX);
+    }
+
+    #[test]
+    fn test_kahan_sigma_precision() {
+        // 1 followed by many values below the f32 resolution at 1
+        let mut elements = vec![1f64];
+        elements.extend(vec![1e-8; 10000]);
+        let naive_f32: f32 = elements.iter().map(|&x| x as f32).sum();
+        assert_eq!(naive_f32, 1.);
+
+        let sum_f32_ = kahan_sigma(elements.iter(), |&x| x as f32);
+        assert!((sum_f32_ - 1.0001).abs() < F32_ERROR_TOLERANCE);
+        assert_eq!(sum_f32_, sum_f32(elements.iter()));
+
+        let sum_f64 = kahan_sigma(elements.iter(), |&x| x);
+        assert!((sum_f64 - 1.0001).abs() < F64_ERROR_TOLERANCE);
+        assert_eq!(sum_f64, sum(elements.iter()));
+        assert_eq!(
+            (sum_f64, elements.len()),
+            kahan_sigma_return_counter(elements.iter(), |&x| x)
+        );
+
+        let elements = [1, 5, 3, 2, 7, 100];
+        assert_eq!(
+            sum_of_squares_f32(elements.iter()),
+            kahan_sigma(elements.iter(), |&x| (x * x) as f32)
+        );
+        assert_eq!(
+            sum_of_squares(elements.iter()) as f32,
+            sum_of_squares_f32(elements.iter())
+        );
+    }
+
     #[test]
     fn test_sum_of_squares() {
         let elements = vec![1, 5, 3, 2, 7, 100];
@@ -294,4 +578,125 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_percentiles_by() {
+        let mut rng = rand::thread_rng();
+        let mut numbers = Vec::from_iter(-100..900);
+        numbers.shuffle(&mut rng);
+        let ratios = [0.25, 0.5, 0.75];
+        let expected: Vec<i32> = ratios
+            .iter()
+            .map(|&r| {
+                percentile_by(numbers.clone(), r, |a, b| a.cmp(b)).unwrap()
+            })
+            .collect();
+        assert_eq!(expected, vec![150, 400, 650]);
+        assert_eq!(
+            percentiles_by(numbers.clone(), &ratios, |a, b| a.cmp(b)),
+            Ok(expected)
+        );
+
+        // order of the ratios is preserved and the extremes are included
+        assert_eq!(
+            percentiles_by(numbers.clone(), &[1., 0.5, 0.], |a, b| a.cmp(b)),
+            Ok(vec![899, 400, -100])
+        );
+        assert_eq!(
+            percentiles_by(numbers.clone(), &[], |a, b| a.cmp(b)),
+            Ok(vec![])
+        );
+
+        let floats = vec![0.3, -0.2, 0.1];
+        assert_eq!(
+            percentiles_by(floats, &[0.4, 0.9], |a, b| a
+                .partial_cmp(b)
+                .unwrap()),
+            Ok(vec![0.1, 0.3])
+        );
+
+        assert!(
+            percentiles_by(Vec::<i32>::new(), &[0.5], |a, b| a.cmp(b)).is_err()
+        );
+        assert!(
+            percentiles_by(numbers.clone(), &[0.5, 1.1], |a, b| a.cmp(b))
+                .is_err()
+        );
+        assert!(percentiles_by(numbers, &[-0.1], |a, b| a.cmp(b)).is_err());
+    }
+
+    #[test]
+    fn test_mode() {
+        assert_eq!(mode(&[1, 2, 2, 3, 3, 3, 4]), Some(3));
+        assert_eq!(mode(&[-5]), Some(-5));
+        assert_eq!(mode::<i32>(&[]), None);
+        // ties are broken by the first occurrence
+        assert_eq!(mode(&[4, 2, 2, 4, 7]), Some(4));
+        assert_eq!(mode(&[1, 2, 3]), Some(1));
+        assert_eq!(mode(&[5, 1, 1, 5, 9, 9, 9, 1]), Some(1));
+
+        let words: Vec<String> = ["to", "be", "or", "not", "to", "be"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        assert_eq!(mode(&words), Some("to".to_string()));
+        assert_eq!(mode(&words[1..]), Some("be".to_string()));
+        assert_eq!(mode(&["x", "y", "y"]), Some("y"));
+    }
+
+    #[test]
+    fn test_min_max_and_range() {
+        let numbers = [4, -7, 12, 0, -3, 9, 12, -7];
+        assert_eq!(min_max(numbers.iter()), Some((-7, 12)));
+        assert_eq!(range(numbers.iter()), Some(19));
+
+        let floats = [-0.5, 2.25, -1.75, 1.];
+        assert_eq!(min_max(floats.iter()), Some((-1.75, 2.25)));
+        assert_eq!(range(floats.iter()), Some(4.));
+
+        assert_eq!(min_max([-4].iter()), Some((-4, -4)));
+        assert_eq!(range([-4].iter()), Some(0));
+        assert_eq!(min_max(Vec::<i32>::new().iter()), None);
+        assert_eq!(range(Vec::<i32>::new().iter()), None);
+    }
+
+    #[test]
+    fn test_trimmed_mean() {
+        let numbers = [-1000., 4., 6., 5., 3., 7., 1e6];
+        assert_eq!(trimmed_mean(&numbers, 0.15), Ok(5.));
+        assert_eq!(trimmed_mean(&numbers, 0.3), Ok(5.));
+        assert_eq!(trimmed_mean(&numbers[1..6], 0.), Ok(5.));
+        assert_eq!(trimmed_mean(&numbers, 0.), Ok(mean(numbers.iter())));
+        // fewer than one element per side to trim
+        assert_eq!(trimmed_mean(&numbers, 0.1), Ok(mean(numbers.iter())));
+        assert_eq!(trimmed_mean(&[2.5], 0.4), Ok(2.5));
+
+        assert!(trimmed_mean(&[], 0.1).is_err());
+        assert!(trimmed_mean(&numbers, 0.5).is_err());
+        assert!(trimmed_mean(&numbers, -0.1).is_err());
+        assert!(trimmed_mean(&[1., f64::NAN, 2.], 0.1).is_err());
+    }
+
+    #[test]
+    fn test_autocorrelation() {
+        // a sequence with period 4 whose mean is 0
+        let series: Vec<f64> =
+            (0..40).map(|t| [1., 0., -1., 0.][t % 4]).collect();
+        let acf = autocorrelation(&series, 9);
+        let expected = [1., 0., -0.95, 0., 0.9, 0., -0.85, 0., 0.8, 0.];
+        assert_eq!(acf.len(), expected.len());
+        for (a, e) in acf.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < F64_ERROR_TOLERANCE, "{} != {}", a, e);
+        }
+        // the peaks are at the multiples of the period
+        for (k, a) in acf.iter().enumerate().skip(1) {
+            assert_eq!(k % 4 == 0, *a > 0.5);
+        }
+
+        let acf = autocorrelation(&[2., 4., 6.], 4);
+        assert_eq!(acf, vec![1., 0., -0.5, 0., 0.]);
+
+        assert!(autocorrelation(&[3., 3.], 1).iter().all(|a| a.is_nan()));
+        assert!(autocorrelation(&[], 0)[0].is_nan());
+    }
 }
@@ -1,4 +1,6 @@
-use crate::tensor::{tensor_shape::TensorShape, tensor_storage::TensorStorage};
+use crate::tensor::{
+    tensor_shape::TensorShape, tensor_storage::TensorStorage, Unitless,
+};
 
 pub trait BorrowTensor<'a, Dtype> {
     type Output;
@@ -6,5 +8,6 @@ pub trait BorrowTensor<'a, Dtype> {
     fn create_borrowed_tensor(
         shape: TensorShape,
         data: &'a TensorStorage<Dtype>,
+        offset: Unitless,
     ) -> Self::Output;
 }
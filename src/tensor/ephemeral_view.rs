@@ -1,11 +1,17 @@
-use crate::tensor::{
-    borrow_tensor::BorrowTensor,
-    tensor_shape::{HasTensorShape, TensorShape},
-    tensor_storage::{HasTensorData, TensorStorage},
+use crate::{
+    tensor::{
+        borrow_tensor::BorrowTensor,
+        tensor_shape::{HasTensorShape, TensorShape},
+        tensor_storage::{HasTensorData, TensorStorage},
+        AxisIndex, Unitless,
+    },
+    traits::ToIterator,
 };
+use num::Num;
 
 /// # A View of the Underlying Referenced Data as a Particular Shape
-/// The underlying `data` has to outlive the `EphemeralView` itself.
+/// The underlying `data` has to outlive the `EphemeralView` itself. The first
+/// element of the view is located at the `offset` index of the `data`.
 ///
 /// ## Examples
 /// ```
@@ -22,6 +28,7 @@ use crate::tensor::{
 pub struct EphemeralView<'a, Dtype> {
     pub shape: TensorShape,
     pub data: &'a TensorStorage<Dtype>,
+    pub offset: Unitless,
 }
 
 /// The implementer can be viewed as a tensor of `shape` through the `as_shape`
@@ -42,8 +49,166 @@ impl<'a, Dtype> EphemeralView<'a, Dtype> {
         EphemeralView {
             shape: shape.into(),
             data,
+            offset: 0,
         }
     }
+
+    /// Returns a view of the elements whose coordinates along the `axis` are in
+    /// `[start, end)`, without copying the underlying data.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::{
+    ///     ephemeral_view::ToEphemeralView, indexable_tensor::IndexableTensor,
+    ///     tensor_storage::IntoTensorStorage,
+    /// };
+    ///
+    /// let storage = vec![1, 2, 3, 4, 5, 6].into_tensor_storage();
+    /// let view = storage.as_shape([3, 2]);
+    /// let rows = view.slice_axis(0, 1, 3);
+    /// assert_eq!(rows.shape.dims(), vec![2, 2]);
+    /// assert_eq!(rows.at([0, 0]), 3);
+    /// assert_eq!(rows.at([1, 1]), 6);
+    /// ```
+    pub fn slice_axis(
+        &self,
+        axis: AxisIndex,
+        start: Unitless,
+        end: Unitless,
+    ) -> EphemeralView<'a, Dtype> {
+        assert!(
+            axis < self.shape.ndim(),
+            "axis ({}) >= ndim ({})",
+            axis,
+            self.shape.ndim()
+        );
+        let (dim, stride) = self.shape.dims_strides[axis];
+        assert!(
+            0 <= start && start <= end && end <= dim,
+            "invalid range [{}, {}) for axis {} of size {}",
+            start,
+            end,
+            axis,
+            dim
+        );
+        let mut shape = self.shape.clone();
+        shape.dims_strides[axis].0 = end - start;
+        EphemeralView {
+            shape,
+            data: self.data,
+            offset: self.offset + start * stride,
+        }
+    }
+
+    /// Returns a view of the same elements in row-major order as the `shape`,
+    /// without copying the underlying data. Returns an error if the number of
+    /// elements differs or if the view is not contiguous, e.g. a transposed
+    /// view.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::{
+    ///     ephemeral_view::ToEphemeralView, indexable_tensor::IndexableTensor,
+    ///     tensor_storage::IntoTensorStorage,
+    /// };
+    ///
+    /// let storage = vec![1, 2, 3, 4, 5, 6].into_tensor_storage();
+    /// let view = storage.as_shape([2, 3]);
+    /// let reshaped = view.reshape([3, 2]).unwrap();
+    /// assert_eq!(reshaped.at([1, 0]), 3);
+    /// assert!(view.reshape([4, 2]).is_err());
+    /// ```
+    pub fn reshape<S: Into<TensorShape>>(
+        &self,
+        shape: S,
+    ) -> Result<EphemeralView<'a, Dtype>, String> {
+        let shape: TensorShape = shape.into();
+        if shape.num_elements() != self.shape.num_elements() {
+            return Err(format!(
+                "cannot reshape {} elements into a shape of {} elements",
+                self.shape.num_elements(),
+                shape.num_elements()
+            ));
+        }
+        if !self.shape.is_contiguous() {
+            return Err("cannot reshape a non-contiguous view".to_string());
+        }
+        Ok(EphemeralView {
+            shape,
+            data: self.data,
+            offset: self.offset,
+        })
+    }
+}
+
+/// Reductions over all the elements of the view. The elements are visited
+/// through `to_iter`, so the results do not depend on the memory layout.
+impl<'a, Dtype> EphemeralView<'a, Dtype>
+where
+    Dtype: Copy + Num + PartialOrd,
+{
+    /// Returns zero if the view is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::{
+    ///     ephemeral_view::ToEphemeralView, tensor_storage::IntoTensorStorage,
+    /// };
+    ///
+    /// let storage = vec![3, -1, 4, 1, -5, 9].into_tensor_storage();
+    /// let view = storage.as_shape([2, 3]);
+    /// assert_eq!(view.sum(), 11);
+    /// assert_eq!(view.max(), Some(9));
+    /// assert_eq!(view.min(), Some(-5));
+    /// ```
+    pub fn sum(&self) -> Dtype {
+        self.to_iter().fold(Dtype::zero(), |acc, x| acc + x)
+    }
+
+    /// Returns `None` if the view is empty.
+    pub fn max(&self) -> Option<Dtype> {
+        self.to_iter().fold(None, |acc, x| match acc {
+            Some(m) if m >= x => Some(m),
+            _ => Some(x),
+        })
+    }
+
+    /// Returns `None` if the view is empty.
+    pub fn min(&self) -> Option<Dtype> {
+        self.to_iter().fold(None, |acc, x| match acc {
+            Some(m) if m <= x => Some(m),
+            _ => Some(x),
+        })
+    }
+}
+
+impl<'a, Dtype> EphemeralView<'a, Dtype>
+where
+    Dtype: Copy + PartialEq,
+{
+    /// Whether the two views have the same dimensions and the same elements
+    /// in row-major order, regardless of their strides, offsets and
+    /// underlying storages. Note that the derived `==` compares the strides
+    /// and the storages instead.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::{
+    ///     ephemeral_view::ToEphemeralView, matrix_transpose::MatrixTranspose,
+    ///     tensor_storage::IntoTensorStorage,
+    /// };
+    ///
+    /// let s1 = vec![1, 2, 3, 4].into_tensor_storage();
+    /// let s2 = vec![1, 3, 2, 4].into_tensor_storage();
+    /// let v1 = s1.as_shape([2, 2]);
+    /// let v2 = s2.as_shape([2, 2]);
+    /// assert_ne!(v1, v2.t());
+    /// assert!(v1.content_eq(&v2.t()));
+    /// ```
+    pub fn content_eq(&self, other: &EphemeralView<Dtype>) -> bool {
+        self.shape.dims() == other.shape.dims()
+            && self.to_iter().eq(other.to_iter())
+    }
 }
 
 impl<Dtype> HasTensorShape for EphemeralView<'_, Dtype> {
@@ -56,6 +221,10 @@ impl<Dtype> HasTensorData<Dtype> for EphemeralView<'_, Dtype> {
     fn data(&self) -> &TensorStorage<Dtype> {
         &self.data
     }
+
+    fn offset(&self) -> Unitless {
+        self.offset
+    }
 }
 
 impl<'a, Dtype: 'a> BorrowTensor<'a, Dtype> for EphemeralView<'_, Dtype> {
@@ -64,16 +233,25 @@ impl<'a, Dtype: 'a> BorrowTensor<'a, Dtype> for EphemeralView<'_, Dtype> {
     fn create_borrowed_tensor(
         shape: TensorShape,
         data_ref: &'a TensorStorage<Dtype>,
+        offset: Unitless,
     ) -> Self::Output {
-        EphemeralView::new(data_ref, shape)
+        EphemeralView {
+            shape,
+            data: data_ref,
+            offset,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::tensor::{
-        ephemeral_view::ToEphemeralView, tensor_shape::TensorShape,
-        tensor_storage::IntoTensorStorage,
+    use crate::{
+        tensor::{
+            ephemeral_view::ToEphemeralView, indexable_tensor::IndexableTensor,
+            matrix_transpose::MatrixTranspose, tensor_shape::TensorShape,
+            tensor_storage::IntoTensorStorage,
+        },
+        traits::ToIterator,
     };
 
     #[test]
@@ -95,4 +273,144 @@ mod tests {
         assert_eq!(view2.shape.strides(), vec![2, 1]);
         assert_eq!(view2.shape.ndim(), 2);
     }
+
+    #[test]
+    fn test_slice_axis() {
+        let storage = (0..12).collect::<Vec<i32>>().into_tensor_storage();
+        let view = storage.as_shape([4, 3]);
+
+        let rows = view.slice_axis(0, 1, 3);
+        assert_eq!(rows.shape, TensorShape {
+            dims_strides: vec![(2, 3), (3, 1)]
+        });
+        assert_eq!(rows.offset, 3);
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(rows.at([i, j]), view.at([i + 1, j]));
+            }
+        }
+        assert_eq!(rows.to_iter().collect::<Vec<i32>>(), vec![
+            3, 4, 5, 6, 7, 8
+        ]);
+
+        // slicing the columns of the sliced rows
+        let sub = rows.slice_axis(1, 1, 3);
+        assert_eq!(sub.to_iter().collect::<Vec<i32>>(), vec![4, 5, 7, 8]);
+        assert_eq!(sub.try_at([1, 1]), Some(8));
+        assert_eq!(sub.try_at([1, 2]), None);
+
+        // transposing preserves the offset
+        let sub_t = sub.t();
+        assert_eq!(sub_t.offset, 4);
+        assert_eq!(sub_t.to_iter().collect::<Vec<i32>>(), vec![4, 7, 5, 8]);
+
+        // slicing a transposed view
+        let view_t = view.t();
+        let columns = view_t.slice_axis(1, 2, 4);
+        assert_eq!(columns.to_iter().collect::<Vec<i32>>(), vec![
+            6, 9, 7, 10, 8, 11
+        ]);
+
+        let empty = view.slice_axis(0, 2, 2);
+        assert_eq!(empty.to_iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_axis_out_of_range() {
+        let storage = (0..12).collect::<Vec<i32>>().into_tensor_storage();
+        storage.as_shape([4, 3]).slice_axis(1, 1, 4);
+    }
+
+    #[test]
+    fn test_reshape() {
+        let storage = (0..12).collect::<Vec<i32>>().into_tensor_storage();
+        let view = storage.as_shape([2, 6]);
+
+        let reshaped = view.reshape([3, 4]).unwrap();
+        assert_eq!(reshaped.shape, TensorShape::from([3, 4]));
+        assert!(std::ptr::eq(reshaped.data, view.data));
+        for i in 0..3 {
+            for j in 0..4 {
+                assert_eq!(reshaped.at([i, j]), (i * 4 + j) as i32);
+            }
+        }
+        assert_eq!(
+            reshaped.to_iter().collect::<Vec<i32>>(),
+            view.to_iter().collect::<Vec<i32>>()
+        );
+        assert_eq!(
+            view.reshape([2, 3, 2]).unwrap().at([1, 2, 1]),
+            view.at([1, 5])
+        );
+
+        // a contiguous slice keeps its offset
+        let rows = storage.as_shape([4, 3]).slice_axis(0, 2, 4);
+        assert_eq!(
+            rows.reshape([6]).unwrap().to_iter().collect::<Vec<i32>>(),
+            vec![6, 7, 8, 9, 10, 11]
+        );
+
+        assert!(view.reshape([5, 2]).is_err());
+        assert!(view.reshape([3, 5]).is_err());
+        assert!(view.t().reshape([3, 4]).is_err());
+    }
+
+    #[test]
+    fn test_reductions() {
+        let storage =
+            vec![2., -7.5, 3., 8., 0.5, -1., 4., 6., -2.].into_tensor_storage();
+        let view = storage.as_shape([3, 3]);
+        let transposed = view.t();
+        let sliced = transposed.slice_axis(1, 1, 3);
+
+        assert_eq!(view.sum(), 13.);
+        assert_eq!(view.max(), Some(8.));
+        assert_eq!(view.min(), Some(-7.5));
+
+        // the reductions do not depend on the layout
+        assert_eq!(transposed.sum(), view.sum());
+        assert_eq!(transposed.max(), view.max());
+        assert_eq!(transposed.min(), view.min());
+
+        // the sliced view of the transpose contains the last two rows
+        assert_eq!(sliced.sum(), 15.5);
+        assert_eq!(sliced.max(), Some(8.));
+        assert_eq!(sliced.min(), Some(-2.));
+
+        let empty = view.slice_axis(0, 1, 1);
+        assert_eq!(empty.sum(), 0.);
+        assert_eq!(empty.max(), None);
+        assert_eq!(empty.min(), None);
+    }
+
+    #[test]
+    fn test_content_eq() {
+        let storage = (0..6).collect::<Vec<i32>>().into_tensor_storage();
+        let view = storage.as_shape([2, 3]);
+        let transposed = view.t();
+        let double_transposed = transposed.t();
+        assert!(view.content_eq(&double_transposed));
+        assert!(double_transposed.content_eq(&view));
+        assert!(view.content_eq(&view));
+        assert!(!view.content_eq(&transposed));
+
+        // same contents laid out differently in another storage
+        let other_storage = vec![0, 3, 1, 4, 2, 5].into_tensor_storage();
+        let other_view = other_storage.as_shape([3, 2]);
+        let other = other_view.t();
+        assert_ne!(view, other);
+        assert!(view.content_eq(&other));
+
+        // same elements in different dimensions
+        assert!(!view.content_eq(&storage.as_shape([3, 2])));
+        assert!(!view.content_eq(&storage.as_shape([6])));
+
+        // a sliced view against a fresh storage
+        let rows = storage.as_shape([3, 2]).slice_axis(0, 1, 3);
+        let expected = vec![2, 3, 4, 5].into_tensor_storage();
+        assert!(rows.content_eq(&expected.as_shape([2, 2])));
+        let corner = view.slice_axis(0, 0, 2).slice_axis(1, 0, 2);
+        assert!(!rows.content_eq(&corner));
+    }
 }
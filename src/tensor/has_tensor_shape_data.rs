@@ -10,7 +10,7 @@ pub trait HasTensorShapeData<Dtype>:
             self.shape().dims_strides.len(),
             "coordinate dimension mismatch"
         );
-        let mut index = 0;
+        let mut index = self.offset();
         for i in 0..self.shape().ndim() {
             index += coord[i] * self.shape().dims_strides[i].1;
         }
@@ -6,6 +6,75 @@ where
     fn at<T: AsRef<[Unitless]>>(&self, coord: T) -> Dtype {
         self.data()[self.coord_to_index(coord.as_ref()) as usize]
     }
+
+    /// Same as `at` except that `None` is returned instead of panicking or
+    /// reading the wrong element if the number of coordinates does not match
+    /// the number of dimensions, or if any of the coordinates is out of the
+    /// range of its dimension.
+    fn try_at<T: AsRef<[Unitless]>>(&self, coord: T) -> Option<Dtype> {
+        let coord = coord.as_ref();
+        let shape = self.shape();
+        if coord.len() != shape.ndim()
+            || coord
+                .iter()
+                .zip(shape.dims_strides.iter())
+                .any(|(&c, &(dim, _))| c < 0 || c >= dim)
+        {
+            return None;
+        }
+        self.data()
+            .vec
+            .get(self.coord_to_index(coord) as usize)
+            .copied()
+    }
+
+    /// Returns whether the two tensors have the same dimensions and the same
+    /// elements at every coordinate. Only the logical contents are compared,
+    /// so the strides, offsets and underlying storages can differ, e.g. a
+    /// transposed view can be equal to an owned matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::{
+    ///     indexable_tensor::IndexableTensor, matrix::Matrix,
+    ///     matrix_transpose::MatrixTranspose,
+    /// };
+    ///
+    /// let a = Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3);
+    /// let a_t = Matrix::from_vec(vec![1, 4, 2, 5, 3, 6], 3, 2);
+    /// assert!(a.t().logical_eq(&a_t));
+    /// assert!(!a.logical_eq(&a_t));
+    /// ```
+    fn logical_eq<T: IndexableTensor<Dtype>>(&self, other: &T) -> bool
+    where
+        Dtype: PartialEq, {
+        let dims = self.shape().dims();
+        if dims != other.shape().dims() {
+            return false;
+        }
+        if dims.contains(&0) {
+            return true;
+        }
+        // iterates over all the coordinates in row-major order
+        let mut coord = vec![0; dims.len()];
+        loop {
+            if self.at(&coord) != other.at(&coord) {
+                return false;
+            }
+            let mut axis = dims.len();
+            loop {
+                if axis == 0 {
+                    return true;
+                }
+                axis -= 1;
+                coord[axis] += 1;
+                if coord[axis] < dims[axis] {
+                    break;
+                }
+                coord[axis] = 0;
+            }
+        }
+    }
 }
 
 impl<Dtype, T> IndexableTensor<Dtype> for T
@@ -19,6 +88,7 @@ where
 mod tests {
     use crate::tensor::{
         ephemeral_view::ToEphemeralView, indexable_tensor::IndexableTensor,
+        matrix::Matrix, matrix_transpose::MatrixTranspose,
         tensor_storage::IntoTensorStorage,
     };
 
@@ -38,4 +108,46 @@ mod tests {
         let coord = vec![1, 2];
         assert_eq!(view.at(&coord), 6);
     }
+
+    #[test]
+    fn test_try_at() {
+        let storage = vec![1, 2, 3, 4, 5, 6].into_tensor_storage();
+        let view = storage.as_shape([2, 3]);
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(view.try_at([i, j]), Some(view.at([i, j])));
+            }
+        }
+        assert_eq!(view.try_at([1, 2]), Some(6));
+        assert_eq!(view.try_at([0, 3]), None);
+        assert_eq!(view.try_at([2, 0]), None);
+        assert_eq!(view.try_at([-1, 0]), None);
+        assert_eq!(view.try_at([0, -1]), None);
+        assert_eq!(view.try_at([0]), None);
+        assert_eq!(view.try_at([0, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_logical_eq() {
+        let a = Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3);
+        let a_t = Matrix::from_vec(vec![1, 4, 2, 5, 3, 6], 3, 2);
+        assert!(a.t().to_owned().logical_eq(&a_t));
+        assert!(a_t.logical_eq(&a.t().to_owned()));
+        assert!(a.logical_eq(&a));
+
+        // same elements but different dimensions
+        assert!(!a.logical_eq(&a_t));
+        let storage = vec![1, 2, 3, 4, 5, 6].into_tensor_storage();
+        assert!(storage.as_shape([2, 3]).logical_eq(&a));
+        assert!(!storage.as_shape([6]).logical_eq(&a));
+        assert!(!storage.as_shape([3, 2]).logical_eq(&a));
+
+        // same dimensions but different elements
+        let b = Matrix::from_vec(vec![1, 4, 2, 5, 3, 7], 3, 2);
+        assert!(!a.t().logical_eq(&b));
+
+        let empty = Matrix::<i32>::from_vec(vec![], 0, 2);
+        assert!(empty.logical_eq(&Matrix::from_vec(vec![], 0, 2)));
+        assert!(!empty.logical_eq(&Matrix::from_vec(vec![], 2, 0)));
+    }
 }
@@ -1,16 +1,21 @@
-use crate::tensor::{
-    ephemeral_view::EphemeralView,
-    has_tensor_shape_data::HasTensorShapeData,
-    indexable_tensor::IndexableTensor,
-    tensor_shape::{HasTensorShape, TensorShape},
-    tensor_storage::{HasTensorData, IntoTensorStorage, TensorStorage},
-    Unitless,
+use crate::{
+    stats::sum_of_squares,
+    tensor::{
+        ephemeral_view::EphemeralView,
+        has_tensor_shape_data::HasTensorShapeData,
+        indexable_tensor::IndexableTensor,
+        tensor_shape::{HasTensorShape, TensorShape},
+        tensor_storage::{HasTensorData, IntoTensorStorage, TensorStorage},
+        AxisIndex, Unitless,
+    },
+    traits::ToIterator,
 };
 use num::Num;
 use std::{
+    cmp::Ordering,
     fmt,
     fmt::Formatter,
-    ops::{Index, IndexMut},
+    ops::{Index, IndexMut, Range},
 };
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -38,6 +43,508 @@ where
             storage: v.into_tensor_storage(),
         }
     }
+
+    /// Returns a matrix of the same shape with `f` applied to every element.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_vec(vec![-1, 2, -3, 4], 2, 2);
+    /// assert_eq!(
+    ///     m.map(|x| if x < 0 { 0 } else { x }),
+    ///     Matrix::from_vec(vec![0, 2, 0, 4], 2, 2)
+    /// );
+    /// ```
+    pub fn map<F: Fn(Dtype) -> Dtype>(&self, f: F) -> Matrix<Dtype> {
+        Matrix {
+            shape: self.shape.clone(),
+            storage: self
+                .storage
+                .vec
+                .iter()
+                .map(|&x| f(x))
+                .collect::<Vec<Dtype>>()
+                .into_tensor_storage(),
+        }
+    }
+
+    /// Raises the square matrix to the `n`-th power by repeated squaring, where
+    /// the `0`-th power is the identity matrix.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// // the Fibonacci matrix
+    /// let a = Matrix::from_vec(vec![1, 1, 1, 0], 2, 2);
+    /// assert_eq!(a.matpow(10), Matrix::from_vec(vec![89, 55, 55, 34], 2, 2));
+    /// ```
+    pub fn matpow(&self, mut n: usize) -> Matrix<Dtype> {
+        let size = self.num_rows();
+        assert_eq!(
+            size,
+            self.num_columns(),
+            "the matrix is not square: {} x {}",
+            size,
+            self.num_columns()
+        );
+        let mut result = Matrix::from_vec(
+            vec![Dtype::zero(); (size * size) as usize],
+            size,
+            size,
+        );
+        for i in 0..size {
+            result[[i, i]] = Dtype::one();
+        }
+        let mut base = self.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.matmul(&base);
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.matmul(&base);
+            }
+        }
+        result
+    }
+
+    /// Stacks `other` below `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_vec(vec![1, 2], 1, 2);
+    /// let b = Matrix::from_vec(vec![3, 4, 5, 6], 2, 2);
+    /// assert_eq!(
+    ///     a.vconcat(&b),
+    ///     Ok(Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 3, 2))
+    /// );
+    /// assert!(a.vconcat(&Matrix::from_vec(vec![1, 2, 3], 1, 3)).is_err());
+    /// ```
+    pub fn vconcat(
+        &self,
+        other: &Matrix<Dtype>,
+    ) -> Result<Matrix<Dtype>, String> {
+        if self.num_columns() != other.num_columns() {
+            return Err(format!(
+                "cannot vertically concatenate a matrix with {} columns and a \
+                 matrix with {} columns",
+                self.num_columns(),
+                other.num_columns()
+            ));
+        }
+        let mut v = Vec::with_capacity(
+            self.storage.vec.len() + other.storage.vec.len(),
+        );
+        v.extend_from_slice(&self.storage.vec);
+        v.extend_from_slice(&other.storage.vec);
+        Ok(Matrix::from_vec(
+            v,
+            self.num_rows() + other.num_rows(),
+            self.num_columns(),
+        ))
+    }
+
+    /// Places `other` to the right of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_vec(vec![1, 2], 2, 1);
+    /// let b = Matrix::from_vec(vec![3, 4, 5, 6], 2, 2);
+    /// assert_eq!(
+    ///     a.hconcat(&b),
+    ///     Ok(Matrix::from_vec(vec![1, 3, 4, 2, 5, 6], 2, 3))
+    /// );
+    /// assert!(a.hconcat(&Matrix::from_vec(vec![1, 2, 3], 3, 1)).is_err());
+    /// ```
+    pub fn hconcat(
+        &self,
+        other: &Matrix<Dtype>,
+    ) -> Result<Matrix<Dtype>, String> {
+        if self.num_rows() != other.num_rows() {
+            return Err(format!(
+                "cannot horizontally concatenate a matrix with {} rows and a \
+                 matrix with {} rows",
+                self.num_rows(),
+                other.num_rows()
+            ));
+        }
+        let mut v = Vec::with_capacity(
+            self.storage.vec.len() + other.storage.vec.len(),
+        );
+        let n1 = self.num_columns() as usize;
+        let n2 = other.num_columns() as usize;
+        for i in 0..self.num_rows() as usize {
+            v.extend_from_slice(&self.storage.vec[i * n1..(i + 1) * n1]);
+            v.extend_from_slice(&other.storage.vec[i * n2..(i + 1) * n2]);
+        }
+        Ok(Matrix::from_vec(
+            v,
+            self.num_rows(),
+            self.num_columns() + other.num_columns(),
+        ))
+    }
+
+    /// Whether the matrix is square and `a[i, j] == a[j, i]` for all `i, j`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// assert!(Matrix::from_vec(vec![1, 2, 2, 3], 2, 2).is_symmetric());
+    /// assert!(!Matrix::from_vec(vec![1, 2, 0, 3], 2, 2).is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool {
+        let n = self.num_rows();
+        n == self.num_columns()
+            && (0..n).all(|i| (0..i).all(|j| self[[i, j]] == self[[j, i]]))
+    }
+
+    /// Whether all the elements off the main diagonal are zero. The matrix
+    /// does not have to be square.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// assert!(Matrix::from_vec(vec![1, 0, 0, 3], 2, 2).is_diagonal());
+    /// assert!(!Matrix::from_vec(vec![1, 2, 2, 3], 2, 2).is_diagonal());
+    /// ```
+    pub fn is_diagonal(&self) -> bool {
+        (0..self.num_rows()).all(|i| {
+            (0..self.num_columns())
+                .all(|j| i == j || self[[i, j]] == Dtype::zero())
+        })
+    }
+
+    /// Swaps the `i`-th and the `j`-th rows in place.
+    ///
+    /// # Panics
+    /// Panics if either `i` or `j` is not in `[0, num_rows)`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// let mut m = Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 3, 2);
+    /// m.swap_rows(0, 2);
+    /// assert_eq!(m, Matrix::from_vec(vec![5, 6, 3, 4, 1, 2], 3, 2));
+    /// ```
+    pub fn swap_rows(&mut self, i: Unitless, j: Unitless) {
+        let num_rows = self.num_rows();
+        assert!(
+            (0..num_rows).contains(&i) && (0..num_rows).contains(&j),
+            "row indices ({}, {}) out of bounds for {} rows",
+            i,
+            j,
+            num_rows
+        );
+        let n = self.num_columns() as usize;
+        let (i, j) = (i as usize, j as usize);
+        for k in 0..n {
+            self.storage.vec.swap(i * n + k, j * n + k);
+        }
+    }
+
+    /// Swaps the `i`-th and the `j`-th columns in place.
+    ///
+    /// # Panics
+    /// Panics if either `i` or `j` is not in `[0, num_columns)`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// let mut m = Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3);
+    /// m.swap_columns(0, 1);
+    /// assert_eq!(m, Matrix::from_vec(vec![2, 1, 3, 5, 4, 6], 2, 3));
+    /// ```
+    pub fn swap_columns(&mut self, i: Unitless, j: Unitless) {
+        let num_columns = self.num_columns();
+        assert!(
+            (0..num_columns).contains(&i) && (0..num_columns).contains(&j),
+            "column indices ({}, {}) out of bounds for {} columns",
+            i,
+            j,
+            num_columns
+        );
+        let n = num_columns as usize;
+        let (i, j) = (i as usize, j as usize);
+        for r in 0..self.num_rows() as usize {
+            self.storage.vec.swap(r * n + i, r * n + j);
+        }
+    }
+
+    /// Returns a copy of the block consisting of the rows in `row_range` and
+    /// the columns in `col_range`, where the ends of the ranges are exclusive.
+    ///
+    /// # Panics
+    /// Panics if either range is decreasing or not within the dimensions of the
+    /// matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3);
+    /// assert_eq!(
+    ///     m.submatrix(0..2, 1..3),
+    ///     Matrix::from_vec(vec![2, 3, 5, 6], 2, 2)
+    /// );
+    /// ```
+    pub fn submatrix(
+        &self,
+        row_range: Range<Unitless>,
+        col_range: Range<Unitless>,
+    ) -> Matrix<Dtype> {
+        let check = |range: &Range<Unitless>, dim: Unitless, name: &str| {
+            assert!(
+                0 <= range.start
+                    && range.start <= range.end
+                    && range.end <= dim,
+                "{} range {:?} is invalid for {} {}s",
+                name,
+                range,
+                dim,
+                name
+            );
+        };
+        check(&row_range, self.num_rows(), "row");
+        check(&col_range, self.num_columns(), "column");
+        let n = self.num_columns() as usize;
+        let (c0, c1) = (col_range.start as usize, col_range.end as usize);
+        let mut v = Vec::with_capacity(
+            ((row_range.end - row_range.start)
+                * (col_range.end - col_range.start)) as usize,
+        );
+        for r in row_range.start as usize..row_range.end as usize {
+            v.extend_from_slice(&self.storage.vec[r * n + c0..r * n + c1]);
+        }
+        Matrix::from_vec(
+            v,
+            row_range.end - row_range.start,
+            col_range.end - col_range.start,
+        )
+    }
+}
+
+impl Matrix<f64> {
+    /// Computes the determinant by Gaussian elimination with partial pivoting.
+    /// Returns `0.0` if the matrix is singular as determined by `lu_decompose`,
+    /// and `NaN` if any entry is `NaN`.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_vec(vec![3., 8., 4., 6.], 2, 2);
+    /// assert!((m.determinant() - -14.).abs() < 1e-12);
+    /// ```
+    pub fn determinant(&self) -> f64 {
+        match self.lu_factorize() {
+            Some((lu, _, sign)) => {
+                let n = self.num_rows() as usize;
+                (0..n).fold(sign, |product, i| product * lu[i * n + i])
+            }
+            None => 0.,
+        }
+    }
+
+    /// The square root of the sum of squares of all the entries.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_vec(vec![1., 2., 2., 4.], 2, 2);
+    /// assert_eq!(m.frobenius_norm(), 5.);
+    /// ```
+    pub fn frobenius_norm(&self) -> f64 {
+        sum_of_squares(self.storage.vec.iter()).sqrt()
+    }
+
+    /// The L2 norm of each row.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// let m = Matrix::from_vec(vec![3., 4., 0., 2.], 2, 2);
+    /// assert_eq!(m.row_norms(), vec![5., 2.]);
+    /// ```
+    pub fn row_norms(&self) -> Vec<f64> {
+        let n = self.num_columns() as usize;
+        (0..self.num_rows() as usize)
+            .map(|i| {
+                sum_of_squares(self.storage.vec[i * n..(i + 1) * n].iter())
+                    .sqrt()
+            })
+            .collect()
+    }
+
+    /// Computes the LU decomposition with partial pivoting, returning
+    /// `(L, U, permutation)` such that `P·A = L·U`, where `L` is lower
+    /// triangular with a unit diagonal, `U` is upper triangular, and the
+    /// `i`-th row of `P·A` is the `permutation[i]`-th row of `A`. Returns
+    /// `None` if the matrix is singular, i.e. if the magnitude of a pivot is at
+    /// most `n * f64::EPSILON` times the largest magnitude in its column of
+    /// `A`.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_vec(vec![0., 1., 2., 3.], 2, 2);
+    /// let (l, u, permutation) = a.lu_decompose().unwrap();
+    /// assert_eq!(permutation, vec![1, 0]);
+    /// assert_eq!(l, Matrix::from_vec(vec![1., 0., 0., 1.], 2, 2));
+    /// assert_eq!(u, Matrix::from_vec(vec![2., 3., 0., 1.], 2, 2));
+    /// ```
+    pub fn lu_decompose(
+        &self,
+    ) -> Option<(Matrix<f64>, Matrix<f64>, Vec<usize>)> {
+        let (lu, permutation, _) = self.lu_factorize()?;
+        let n = permutation.len();
+        let mut l = vec![0.; n * n];
+        let mut u = vec![0.; n * n];
+        for i in 0..n {
+            for j in 0..n {
+                match i.cmp(&j) {
+                    Ordering::Greater => l[i * n + j] = lu[i * n + j],
+                    Ordering::Equal => {
+                        l[i * n + j] = 1.;
+                        u[i * n + j] = lu[i * n + j];
+                    }
+                    Ordering::Less => u[i * n + j] = lu[i * n + j],
+                }
+            }
+        }
+        let n = n as Unitless;
+        Some((
+            Matrix::from_vec(l, n, n),
+            Matrix::from_vec(u, n, n),
+            permutation,
+        ))
+    }
+
+    /// Solves the linear system `self·x = b` for the column vector `x` by
+    /// forward and back substitution on the LU decomposition of `self`.
+    /// Returns `None` if `self` is singular.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square or if `b` is not an `n x 1` matrix where
+    /// `n` is the number of rows of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::Matrix;
+    ///
+    /// // x + y = 3, x - y = 1
+    /// let a = Matrix::from_vec(vec![1., 1., 1., -1.], 2, 2);
+    /// let b = Matrix::from_vec(vec![3., 1.], 2, 1);
+    /// assert_eq!(a.solve(&b), Some(Matrix::from_vec(vec![2., 1.], 2, 1)));
+    /// ```
+    pub fn solve(&self, b: &Matrix<f64>) -> Option<Matrix<f64>> {
+        assert_eq!(
+            (b.num_rows(), b.num_columns()),
+            (self.num_rows(), 1),
+            "b must be a column vector with as many rows as the matrix"
+        );
+        let (lu, permutation, _) = self.lu_factorize()?;
+        let n = permutation.len();
+        // forward substitution for L·y = P·b
+        let mut x: Vec<f64> =
+            permutation.iter().map(|&i| b[[i as Unitless, 0]]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= lu[i * n + j] * x[j];
+            }
+        }
+        // back substitution for U·x = y
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] -= lu[i * n + j] * x[j];
+            }
+            x[i] /= lu[i * n + i];
+        }
+        Some(Matrix::from_vec(x, n as Unitless, 1))
+    }
+
+    /// Factorizes the row permuted matrix into the product of a unit lower
+    /// triangular matrix and an upper triangular matrix. Returns
+    /// `(lu, permutation, sign)` where `lu` holds the strictly lower part of
+    /// `L` and all of `U` in row-major order, the `i`-th row of the permuted
+    /// matrix is the `permutation[i]`-th row of `self`, and `sign` is the
+    /// parity of the permutation. Returns `None` if the matrix is singular.
+    /// The tolerance for the pivots is relative to the scale of each column,
+    /// so that badly scaled but invertible matrices are still factorized.
+    fn lu_factorize(&self) -> Option<(Vec<f64>, Vec<usize>, f64)> {
+        let n = self.num_rows();
+        assert_eq!(
+            n,
+            self.num_columns(),
+            "the matrix is not square: {} x {}",
+            n,
+            self.num_columns()
+        );
+        let n = n as usize;
+        let mut lu: Vec<f64> = (0..n as Unitless)
+            .flat_map(|i| (0..n as Unitless).map(move |j| [i, j]))
+            .map(|coord| self[coord])
+            .collect();
+        // `f64::max` ignores NaN, so a NaN entry does not affect the tolerance
+        let tolerances: Vec<f64> = (0..n)
+            .map(|j| {
+                let column_max =
+                    (0..n).map(|i| lu[i * n + j].abs()).fold(0., f64::max);
+                n as f64 * f64::EPSILON * column_max
+            })
+            .collect();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = 1.;
+        for k in 0..n {
+            // `total_cmp` ranks NaN above every number, so a NaN entry becomes
+            // the pivot and propagates into the results instead of panicking
+            let pivot_row = (k..n)
+                .max_by(|&a, &b| {
+                    lu[a * n + k].abs().total_cmp(&lu[b * n + k].abs())
+                })
+                .unwrap();
+            if lu[pivot_row * n + k].abs() <= tolerances[k] {
+                return None;
+            }
+            if pivot_row != k {
+                for j in 0..n {
+                    lu.swap(k * n + j, pivot_row * n + j);
+                }
+                permutation.swap(k, pivot_row);
+                sign = -sign;
+            }
+            let pivot = lu[k * n + k];
+            for i in k + 1..n {
+                let factor = lu[i * n + k] / pivot;
+                lu[i * n + k] = factor;
+                for j in k + 1..n {
+                    lu[i * n + j] -= factor * lu[k * n + j];
+                }
+            }
+        }
+        Some((lu, permutation, sign))
+    }
 }
 
 impl<Dtype> HasTensorShape for Matrix<Dtype> {
@@ -106,6 +613,77 @@ where
         }
         result
     }
+
+    /// Computes the Kronecker product, i.e. the `(m * p) x (n * q)` block
+    /// matrix whose `(i, j)`-th block is `self[[i, j]] * other`, where
+    /// `self` is `m x n` and `other` is `p x q`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::{IndexableMatrix, Matrix};
+    ///
+    /// let a = Matrix::from_vec(vec![1, 2], 1, 2);
+    /// let b = Matrix::from_vec(vec![1, 10], 2, 1);
+    /// assert_eq!(a.kron(&b), Matrix::from_vec(vec![1, 2, 10, 20], 2, 2));
+    /// ```
+    fn kron<R>(&self, other: &R) -> Matrix<Dtype>
+    where
+        R: MatrixTrait<Dtype> + IndexableTensor<Dtype>, {
+        let m = self.num_rows();
+        let n = self.num_columns();
+        let p = other.num_rows();
+        let q = other.num_columns();
+        let mut result = Matrix::from_vec(
+            vec![Dtype::zero(); (m * p * n * q) as usize],
+            m * p,
+            n * q,
+        );
+        for i in 0..m {
+            for j in 0..n {
+                let scale = self.at([i, j]);
+                for k in 0..p {
+                    for l in 0..q {
+                        result[[i * p + k, j * q + l]] =
+                            scale * other.at([k, l]);
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Collapses the `axis` by summation. Since a `Matrix` always has two
+    /// dimensions, the collapsed axis is kept with a size of 1, i.e.
+    /// `sum_axis(0)` returns the column sums as a `1 x num_columns` matrix and
+    /// `sum_axis(1)` returns the row sums as a `num_rows x 1` matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::matrix::{IndexableMatrix, Matrix};
+    ///
+    /// let m = Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3);
+    /// assert_eq!(m.sum_axis(0), Matrix::from_vec(vec![5, 7, 9], 1, 3));
+    /// assert_eq!(m.sum_axis(1), Matrix::from_vec(vec![6, 15], 2, 1));
+    /// ```
+    fn sum_axis(&self, axis: AxisIndex) -> Matrix<Dtype> {
+        assert!(axis < 2, "axis ({}) >= ndim (2)", axis);
+        let m = self.num_rows();
+        let n = self.num_columns();
+        let (num_rows, num_columns) = if axis == 0 { (1, n) } else { (m, 1) };
+        let mut result = Matrix::from_vec(
+            vec![Dtype::zero(); (num_rows * num_columns) as usize],
+            num_rows,
+            num_columns,
+        );
+        for i in 0..m {
+            for j in 0..n {
+                let target = if axis == 0 { [0, j] } else { [i, 0] };
+                let old = result[target];
+                result[target] = old + self.at([i, j]);
+            }
+        }
+        result
+    }
 }
 
 impl<Dtype, T> IndexableMatrix<Dtype> for T
@@ -140,6 +718,43 @@ impl<'a, Dtype> From<&'a Matrix<Dtype>> for EphemeralView<'a, Dtype> {
     }
 }
 
+/// Copies the elements of a 2-dimensional view into an owned matrix in the
+/// row-major order of the view's shape, i.e. a transposed view yields the
+/// transposed matrix.
+///
+/// # Panics
+/// Panics if the view is not 2-dimensional.
+///
+/// # Example
+/// ```
+/// use math::tensor::{
+///     ephemeral_view::ToEphemeralView, matrix::Matrix,
+///     matrix_transpose::MatrixTranspose, tensor_storage::IntoTensorStorage,
+/// };
+///
+/// let storage = vec![1, 2, 3, 4, 5, 6].into_tensor_storage();
+/// let view = storage.as_shape([2, 3]);
+/// assert_eq!(
+///     Matrix::from(&view.t()),
+///     Matrix::from_vec(vec![1, 4, 2, 5, 3, 6], 3, 2)
+/// );
+/// ```
+impl<'a, Dtype> From<&EphemeralView<'a, Dtype>> for Matrix<Dtype>
+where
+    Dtype: Copy + Num,
+{
+    fn from(view: &EphemeralView<'a, Dtype>) -> Self {
+        assert_eq!(
+            view.shape.ndim(),
+            2,
+            "cannot convert a {}-dimensional view into a matrix",
+            view.shape.ndim()
+        );
+        let dims = view.shape.dims();
+        Matrix::from_vec(view.to_iter().collect(), dims[0], dims[1])
+    }
+}
+
 impl<Dtype> fmt::Display for Matrix<Dtype>
 where
     Dtype: Copy + Num + fmt::Display,
@@ -170,7 +785,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tensor::matrix_transpose::MatrixTranspose;
+    use crate::tensor::{
+        ephemeral_view::ToEphemeralView, matrix_transpose::MatrixTranspose,
+    };
 
     #[test]
     fn test_matmul() {
@@ -188,6 +805,458 @@ mod tests {
         assert_eq!(res, Matrix::from_vec(vec![10, 14, 14, 20], 2, 2));
     }
 
+    #[test]
+    fn test_sum_axis() {
+        let a =
+            Matrix::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12], 3, 4);
+        assert_eq!(a.sum_axis(0), Matrix::from_vec(vec![15, 18, 21, 24], 1, 4));
+        assert_eq!(a.sum_axis(1), Matrix::from_vec(vec![10, 26, 42], 3, 1));
+
+        // the transpose is a 4x3 matrix
+        assert_eq!(a.t().sum_axis(0), Matrix::from_vec(vec![10, 26, 42], 1, 3));
+        assert_eq!(
+            a.t().sum_axis(1),
+            Matrix::from_vec(vec![15, 18, 21, 24], 4, 1)
+        );
+    }
+
+    #[test]
+    fn test_determinant() {
+        fn test(v: Vec<f64>, n: Unitless, expected: f64) {
+            let m = Matrix::from_vec(v, n, n);
+            assert!(
+                (m.determinant() - expected).abs() < 1e-9,
+                "{} != {}",
+                m.determinant(),
+                expected
+            );
+        }
+        test(vec![7.], 1, 7.);
+        test(vec![1., 2., 3., 4.], 2, -2.);
+        test(vec![0., 1., 1., 0.], 2, -1.);
+        test(vec![2., -3., 1., 2., 0., -1., 1., 4., 5.], 3, 49.);
+        test(vec![6., 1., 1., 4., -2., 5., 2., 8., 7.], 3, -306.);
+        // requires a row swap for the first pivot
+        test(vec![0., 2., 1., 1., 1., 1., 2., 1., 0.], 3, 3.);
+        test(
+            vec![
+                1., 0., 2., -1., 3., 0., 0., 5., 2., 1., 4., -3., 1., 0., 5.,
+                0.,
+            ],
+            4,
+            30.,
+        );
+
+        // singular matrices
+        test(vec![1., 2., 2., 4.], 2, 0.);
+        test(vec![1., 2., 3., 4., 5., 6., 7., 8., 9.], 3, 0.);
+        test(vec![0., 0., 0., 0.], 2, 0.);
+        assert_eq!(
+            Matrix::from_vec(vec![1., 2., 3., 4., 5., 6., 7., 8., 9.], 3, 3)
+                .determinant(),
+            0.
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_determinant_non_square() {
+        Matrix::from_vec(vec![1., 2., 3., 4., 5., 6.], 2, 3).determinant();
+    }
+
+    #[test]
+    fn test_lu_decompose() {
+        fn test(v: Vec<f64>, n: Unitless) {
+            let a = Matrix::from_vec(v, n, n);
+            let (l, u, permutation) = a.lu_decompose().unwrap();
+            let mut sorted_permutation = permutation.clone();
+            sorted_permutation.sort();
+            assert_eq!(sorted_permutation, (0..n as usize).collect::<Vec<_>>());
+            for i in 0..n {
+                assert_eq!(l[[i, i]], 1.);
+                for j in i + 1..n {
+                    assert_eq!(l[[i, j]], 0.);
+                    assert_eq!(u[[j, i]], 0.);
+                }
+            }
+            let lu = l.matmul(&u);
+            for i in 0..n {
+                for j in 0..n {
+                    let pa = a[[permutation[i as usize] as Unitless, j]];
+                    assert!((pa - lu[[i, j]]).abs() < 1e-9);
+                }
+            }
+        }
+        test(vec![2., -3., 1., 2., 0., -1., 1., 4., 5.], 3);
+        test(vec![0., 2., 1., 1., 1., 1., 2., 1., 0.], 3);
+        test(vec![1., 2., 3., 4.], 2);
+        test(vec![5.], 1);
+        test(
+            vec![
+                1., 0., 2., -1., 3., 0., 0., 5., 2., 1., 4., -3., 1., 0., 5.,
+                0.,
+            ],
+            4,
+        );
+
+        let a =
+            Matrix::from_vec(vec![0., 2., 1., 1., 1., 1., 2., 1., 0.], 3, 3);
+        assert_eq!(a.lu_decompose().unwrap().2, vec![2, 0, 1]);
+
+        let singular =
+            Matrix::from_vec(vec![1., 2., 3., 2., 4., 6., 1., 0., 1.], 3, 3);
+        assert_eq!(singular.lu_decompose(), None);
+        // the last pivot is a rounding error rather than exactly zero
+        let singular =
+            Matrix::from_vec(vec![1., 2., 3., 4., 5., 6., 7., 8., 9.], 3, 3);
+        assert_eq!(singular.lu_decompose(), None);
+    }
+
+    #[test]
+    fn test_lu_badly_scaled() {
+        let a = Matrix::from_vec(vec![1., 0., 0., 1e-20], 2, 2);
+        assert_eq!(a.determinant(), 1e-20);
+        let (l, u, permutation) = a.lu_decompose().unwrap();
+        assert_eq!(l, Matrix::from_vec(vec![1., 0., 0., 1.], 2, 2));
+        assert_eq!(u, a);
+        assert_eq!(permutation, vec![0, 1]);
+        let x = a.solve(&Matrix::from_vec(vec![2., 3e-20], 2, 1)).unwrap();
+        assert_eq!(x[[0, 0]], 2.);
+        assert!((x[[1, 0]] - 3.).abs() < 1e-12);
+
+        let b = Matrix::from_vec(vec![1e-30, 2e-30, 3e-30, 1e-30], 2, 2);
+        assert!((b.determinant() - -5e-60).abs() < 1e-72);
+    }
+
+    #[test]
+    fn test_lu_with_nan() {
+        let a = Matrix::from_vec(vec![1., f64::NAN, 3., 4.], 2, 2);
+        assert!(a.determinant().is_nan());
+        let x = a.solve(&Matrix::from_vec(vec![1., 2.], 2, 1)).unwrap();
+        assert!(x.data().vec.iter().any(|x| x.is_nan()));
+
+        let a = Matrix::from_vec(vec![f64::NAN, 1., 2., 3.], 2, 2);
+        assert!(a.determinant().is_nan());
+        let (_, u, _) = a.lu_decompose().unwrap();
+        assert!(u.data().vec.iter().any(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_solve() {
+        // 2x - 3y + z = 1, 2x - z = 0, x + 4y + 5z = 15 has the solution
+        // (1, 1, 2)
+        let a =
+            Matrix::from_vec(vec![2., -3., 1., 2., 0., -1., 1., 4., 5.], 3, 3);
+        let b = Matrix::from_vec(vec![1., 0., 15.], 3, 1);
+        let x = a.solve(&b).unwrap();
+        for (actual, expected) in x.data().vec.iter().zip([1., 1., 2.].iter()) {
+            assert!((actual - expected).abs() < 1e-9);
+        }
+        let ax = a.matmul(&x);
+        for i in 0..3 {
+            assert!((ax[[i, 0]] - b[[i, 0]]).abs() < 1e-9);
+        }
+
+        // requires pivoting
+        let a =
+            Matrix::from_vec(vec![0., 2., 1., 1., 1., 1., 2., 1., 0.], 3, 3);
+        let b = Matrix::from_vec(vec![1., -2., 7.], 3, 1);
+        let ax = a.matmul(&a.solve(&b).unwrap());
+        for i in 0..3 {
+            assert!((ax[[i, 0]] - b[[i, 0]]).abs() < 1e-9);
+        }
+
+        let singular = Matrix::from_vec(vec![1., 2., 2., 4.], 2, 2);
+        assert_eq!(singular.solve(&Matrix::from_vec(vec![1., 2.], 2, 1)), None);
+        let singular =
+            Matrix::from_vec(vec![1., 2., 3., 4., 5., 6., 7., 8., 9.], 3, 3);
+        let b = Matrix::from_vec(vec![1., 2., 3.], 3, 1);
+        assert_eq!(singular.solve(&b), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_solve_dimension_mismatch() {
+        let a = Matrix::from_vec(vec![1., 1., 1., -1.], 2, 2);
+        a.solve(&Matrix::from_vec(vec![1., 2., 3.], 3, 1));
+    }
+
+    #[test]
+    fn test_kron() {
+        let a = Matrix::from_vec(vec![1, 2, 3, 4], 2, 2);
+        let b = Matrix::from_vec(vec![0, 5, 6, 7], 2, 2);
+        assert_eq!(
+            a.kron(&b),
+            Matrix::from_vec(
+                vec![
+                    0, 5, 0, 10, //
+                    6, 7, 12, 14, //
+                    0, 15, 0, 20, //
+                    18, 21, 24, 28,
+                ],
+                4,
+                4
+            )
+        );
+
+        let identity = Matrix::from_vec(vec![1, 0, 0, 1], 2, 2);
+        assert_eq!(
+            identity.kron(&a),
+            Matrix::from_vec(
+                vec![
+                    1, 2, 0, 0, //
+                    3, 4, 0, 0, //
+                    0, 0, 1, 2, //
+                    0, 0, 3, 4,
+                ],
+                4,
+                4
+            )
+        );
+        assert_eq!(
+            a.kron(&identity),
+            Matrix::from_vec(
+                vec![
+                    1, 0, 2, 0, //
+                    0, 1, 0, 2, //
+                    3, 0, 4, 0, //
+                    0, 3, 0, 4,
+                ],
+                4,
+                4
+            )
+        );
+
+        // non-square shapes and a transposed view
+        let c = Matrix::from_vec(vec![1, 2, 3], 1, 3);
+        let d = Matrix::from_vec(vec![1, -1], 2, 1);
+        let cd = c.kron(&d);
+        assert_eq!(cd, Matrix::from_vec(vec![1, 2, 3, -1, -2, -3], 2, 3));
+        assert_eq!(
+            c.t().kron(&d),
+            Matrix::from_vec(vec![1, -1, 2, -2, 3, -3], 6, 1)
+        );
+    }
+
+    #[test]
+    fn test_concat() {
+        let a = Matrix::from_vec(vec![1, 2, 3, 4], 2, 2);
+        let b = Matrix::from_vec(vec![5, 6, 7, 8], 2, 2);
+        assert_eq!(
+            a.vconcat(&b),
+            Ok(Matrix::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8], 4, 2))
+        );
+        assert_eq!(
+            a.hconcat(&b),
+            Ok(Matrix::from_vec(vec![1, 2, 5, 6, 3, 4, 7, 8], 2, 4))
+        );
+        assert_eq!(
+            b.hconcat(&a),
+            Ok(Matrix::from_vec(vec![5, 6, 1, 2, 7, 8, 3, 4], 2, 4))
+        );
+
+        let c = Matrix::from_vec(vec![9, 10, 11], 1, 3);
+        assert!(a.vconcat(&c).is_err());
+        assert!(a.hconcat(&c).is_err());
+
+        let empty = Matrix::from_vec(vec![], 0, 2);
+        assert_eq!(a.vconcat(&empty), Ok(a.clone()));
+        assert_eq!(empty.vconcat(&a), Ok(a));
+    }
+
+    #[test]
+    fn test_map() {
+        let m = Matrix::from_vec(vec![1, -2, 3, -4, 5, -6], 2, 3);
+        let squared = m.map(|x| x * x);
+        assert_eq!(squared, Matrix::from_vec(vec![1, 4, 9, 16, 25, 36], 2, 3));
+        assert_eq!(squared.num_rows(), 2);
+        assert_eq!(squared.num_columns(), 3);
+
+        let m = Matrix::from_vec(vec![0.5f64, 1.5, 2.5], 3, 1);
+        assert_eq!(
+            m.map(|x| x.min(1.)),
+            Matrix::from_vec(vec![0.5, 1., 1.], 3, 1)
+        );
+    }
+
+    #[test]
+    fn test_matpow() {
+        let a = Matrix::from_vec(vec![1, 2, 3, 4], 2, 2);
+        assert_eq!(a.matpow(0), Matrix::from_vec(vec![1, 0, 0, 1], 2, 2));
+        assert_eq!(a.matpow(1), a);
+        assert_eq!(a.matpow(2), a.matmul(&a));
+        assert_eq!(a.matpow(3), a.matmul(&a).matmul(&a));
+        let mut expected = a.clone();
+        for n in 2..10 {
+            expected = expected.matmul(&a);
+            assert_eq!(a.matpow(n), expected);
+        }
+
+        let b = Matrix::from_vec(vec![0.5, 0.5, 0.25, 0.75], 2, 2);
+        assert_eq!(b.matpow(3), b.matmul(&b).matmul(&b));
+
+        let empty: Matrix<i32> = Matrix::from_vec(vec![], 0, 0);
+        assert_eq!(empty.matpow(3), empty);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matpow_non_square() {
+        Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).matpow(2);
+    }
+
+    #[test]
+    fn test_is_symmetric_and_is_diagonal() {
+        let symmetric = Matrix::from_vec(vec![1, 2, 3, 2, 5, 6, 3, 6, 9], 3, 3);
+        assert!(symmetric.is_symmetric());
+        assert!(!symmetric.is_diagonal());
+
+        let non_symmetric =
+            Matrix::from_vec(vec![1, 2, 3, 2, 5, 6, 3, 7, 9], 3, 3);
+        assert!(!non_symmetric.is_symmetric());
+        assert!(!non_symmetric.is_diagonal());
+
+        let diagonal =
+            Matrix::from_vec(vec![1., 0., 0., 0., -2., 0., 0., 0., 0.], 3, 3);
+        assert!(diagonal.is_symmetric());
+        assert!(diagonal.is_diagonal());
+
+        let rectangular = Matrix::from_vec(vec![4, 0, 0, 0, 5, 0], 2, 3);
+        assert!(!rectangular.is_symmetric());
+        assert!(rectangular.is_diagonal());
+        assert!(!Matrix::from_vec(vec![4, 0, 1, 0, 5, 0], 2, 3).is_diagonal());
+
+        let empty: Matrix<i32> = Matrix::from_vec(vec![], 0, 0);
+        assert!(empty.is_symmetric());
+        assert!(empty.is_diagonal());
+    }
+
+    #[test]
+    fn test_swap_rows_and_columns() {
+        let original =
+            Matrix::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12], 3, 4);
+
+        let mut m = original.clone();
+        m.swap_rows(0, 2);
+        assert_eq!(
+            m,
+            Matrix::from_vec(vec![9, 10, 11, 12, 5, 6, 7, 8, 1, 2, 3, 4], 3, 4)
+        );
+        m.swap_rows(2, 0);
+        assert_eq!(m, original);
+        m.swap_rows(1, 1);
+        assert_eq!(m, original);
+
+        m.swap_columns(1, 3);
+        assert_eq!(
+            m,
+            Matrix::from_vec(vec![1, 4, 3, 2, 5, 8, 7, 6, 9, 12, 11, 10], 3, 4)
+        );
+        m.swap_columns(1, 3);
+        assert_eq!(m, original);
+        m.swap_columns(2, 2);
+        assert_eq!(m, original);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_swap_rows_out_of_bounds() {
+        Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3).swap_rows(0, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_swap_columns_out_of_bounds() {
+        Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 3, 2).swap_columns(2, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_swap_rows_negative_index() {
+        Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 3, 2).swap_rows(-1, 0);
+    }
+
+    #[test]
+    fn test_submatrix() {
+        let m = Matrix::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3);
+        assert_eq!(
+            m.submatrix(1..3, 1..3),
+            Matrix::from_vec(vec![5, 6, 8, 9], 2, 2)
+        );
+        assert_eq!(
+            m.submatrix(0..2, 0..2),
+            Matrix::from_vec(vec![1, 2, 4, 5], 2, 2)
+        );
+        assert_eq!(
+            m.submatrix(0..3, 2..3),
+            Matrix::from_vec(vec![3, 6, 9], 3, 1)
+        );
+        assert_eq!(
+            m.submatrix(1..2, 0..3),
+            Matrix::from_vec(vec![4, 5, 6], 1, 3)
+        );
+        assert_eq!(m.submatrix(0..3, 0..3), m);
+
+        let empty = m.submatrix(2..2, 0..3);
+        assert_eq!(empty.num_rows(), 0);
+        assert_eq!(empty.num_columns(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "column range")]
+    fn test_submatrix_out_of_bounds() {
+        Matrix::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3)
+            .submatrix(1..3, 2..4);
+    }
+
+    #[test]
+    fn test_from_ephemeral_view() {
+        let m = Matrix::from_vec(vec![1, 2, 3, 4, 5, 6], 2, 3);
+        let view = EphemeralView::from(&m);
+        assert_eq!(Matrix::from(&view), m);
+
+        let transposed = view.t();
+        let m_t = Matrix::from(&transposed);
+        assert_eq!(m_t, Matrix::from_vec(vec![1, 4, 2, 5, 3, 6], 3, 2));
+        assert_eq!(m_t.num_rows(), 3);
+        assert_eq!(m_t.num_columns(), 2);
+        for i in 0..3 {
+            for j in 0..2 {
+                assert_eq!(m_t[[i, j]], m[[j, i]]);
+            }
+        }
+        // the owned matrix is laid out in row-major order
+        assert_eq!(m_t.data().vec, vec![1, 4, 2, 5, 3, 6]);
+
+        let sliced = transposed.slice_axis(0, 1, 3);
+        assert_eq!(
+            Matrix::from(&sliced),
+            Matrix::from_vec(vec![2, 5, 3, 6], 2, 2)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "3-dimensional")]
+    fn test_from_ephemeral_view_non_2d() {
+        let storage = (0..8).collect::<Vec<i32>>().into_tensor_storage();
+        let _ = Matrix::from(&storage.as_shape([2, 2, 2]));
+    }
+
+    #[test]
+    fn test_norms() {
+        let m = Matrix::from_vec(vec![1., -2., 2., 0., 3., -4.], 2, 3);
+        assert!((m.frobenius_norm() - 34f64.sqrt()).abs() < 1e-12);
+        assert_eq!(m.row_norms(), vec![3., 5.]);
+
+        let m = Matrix::from_vec(vec![1., 1., 1., 1.], 1, 4);
+        assert_eq!(m.frobenius_norm(), 2.);
+        assert_eq!(m.row_norms(), vec![2.]);
+
+        let m = Matrix::from_vec(vec![1., -1., 2.], 3, 1);
+        assert_eq!(m.frobenius_norm(), 6f64.sqrt());
+        assert_eq!(m.row_norms(), vec![1., 1., 2.]);
+    }
+
     #[test]
     fn test_print_matrix() {
         fn get_display_string(
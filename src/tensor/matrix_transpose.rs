@@ -10,7 +10,11 @@ pub trait MatrixTranspose<'a, Dtype: 'a>:
         let transposed_axes: Vec<AxisIndex> =
             (0..self.shape().ndim()).into_iter().rev().collect();
         let shape_transpose = self.shape().to_transposed(transposed_axes);
-        Self::create_borrowed_tensor(shape_transpose, &self.data())
+        Self::create_borrowed_tensor(
+            shape_transpose,
+            self.data(),
+            self.offset(),
+        )
     }
 
     /// # Arguments
@@ -24,7 +28,8 @@ pub trait MatrixTranspose<'a, Dtype: 'a>:
     ) -> <Self as BorrowTensor<'a, Dtype>>::Output {
         Self::create_borrowed_tensor(
             self.shape().to_transposed(axes),
-            &self.data(),
+            self.data(),
+            self.offset(),
         )
     }
 }
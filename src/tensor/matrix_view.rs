@@ -10,6 +10,7 @@ use crate::tensor::{
 pub struct MatrixView<'a, Dtype> {
     shape: TensorShape,
     data: &'a TensorStorage<Dtype>,
+    offset: Unitless,
 }
 
 impl<'a, Dtype: 'a> BorrowTensor<'a, Dtype> for Matrix<Dtype> {
@@ -18,10 +19,12 @@ impl<'a, Dtype: 'a> BorrowTensor<'a, Dtype> for Matrix<Dtype> {
     fn create_borrowed_tensor(
         shape: TensorShape,
         data: &'a TensorStorage<Dtype>,
+        offset: Unitless,
     ) -> Self::Output {
         MatrixView {
             shape,
             data,
+            offset,
         }
     }
 }
@@ -30,6 +33,10 @@ impl<'a, Dtype> HasTensorData<Dtype> for MatrixView<'a, Dtype> {
     fn data(&self) -> &TensorStorage<Dtype> {
         &self.data
     }
+
+    fn offset(&self) -> Unitless {
+        self.offset
+    }
 }
 
 impl<'a, Dtype> HasTensorShape for MatrixView<'a, Dtype> {
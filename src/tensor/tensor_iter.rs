@@ -1,8 +1,14 @@
 use crate::{tensor::ephemeral_view::EphemeralView, traits::ToIterator};
 
+/// Iterates over the elements of a tensor view in the row-major order of its
+/// shape, which can differ from the order of the underlying data if the view
+/// is transposed.
 pub struct TensorIter<'a, Dtype> {
     i: i64,
     num_elements: i64,
+    /// whether the view is laid out in the row-major order, in which case the
+    /// `i`-th element is located at `offset + i` of the data
+    is_contiguous: bool,
     tensor_view: EphemeralView<'a, Dtype>,
 }
 
@@ -11,9 +17,22 @@ impl<'a, Dtype> TensorIter<'a, Dtype> {
         TensorIter {
             i: 0,
             num_elements: tensor.shape.num_elements() as i64,
+            is_contiguous: tensor.shape.is_contiguous(),
             tensor_view: tensor,
         }
     }
+
+    /// Computes the index into the data for the `i`-th element following the
+    /// strides of each dimension
+    fn strided_index(&self, i: i64) -> i64 {
+        let mut vec_index = self.tensor_view.offset;
+        let mut index = i;
+        for (len, stride) in self.tensor_view.shape.dims_strides.iter().rev() {
+            vec_index += (index % len) * stride;
+            index /= len;
+        }
+        vec_index
+    }
 }
 
 impl<'a, Dtype> Iterator for TensorIter<'a, Dtype>
@@ -26,14 +45,11 @@ where
         if self.i >= self.num_elements {
             None
         } else {
-            let mut vec_index = 0;
-            let mut index = self.i;
-            for (len, stride) in
-                self.tensor_view.shape.dims_strides.iter().rev()
-            {
-                vec_index += (index % len) * stride;
-                index /= len;
-            }
+            let vec_index = if self.is_contiguous {
+                self.tensor_view.offset + self.i
+            } else {
+                self.strided_index(self.i)
+            };
             self.i += 1;
             Some(self.tensor_view.data[vec_index as usize])
         }
@@ -55,6 +71,7 @@ mod tests {
     use crate::{
         tensor::{
             ephemeral_view::{EphemeralView, ToEphemeralView},
+            indexable_tensor::IndexableTensor,
             matrix::Matrix,
             matrix_transpose::MatrixTranspose,
             tensor_storage::IntoTensorStorage,
@@ -100,4 +117,46 @@ mod tests {
             assert_eq!(val, expected);
         }
     }
+
+    #[test]
+    fn test_contiguous_fast_path() {
+        let storage = (0..60).collect::<Vec<i32>>().into_tensor_storage();
+        let view = storage.as_shape([3, 4, 5]);
+
+        let iter = view.to_iter();
+        assert!(iter.is_contiguous);
+        for i in 0..60 {
+            assert_eq!(iter.strided_index(i), i);
+        }
+        assert_eq!(view.to_iter().collect::<Vec<i32>>(), storage.vec);
+
+        // the fast path also applies to views sliced along the first axis
+        let sliced = view.slice_axis(0, 1, 3);
+        let iter = sliced.to_iter();
+        assert!(iter.is_contiguous);
+        assert_eq!(iter.collect::<Vec<i32>>(), (20..60).collect::<Vec<i32>>());
+
+        let transposed = view.transpose(vec![2, 0, 1]);
+        let iter = transposed.to_iter();
+        assert!(!iter.is_contiguous);
+        let mut expected = Vec::new();
+        for k in 0..5 {
+            for i in 0..3 {
+                for j in 0..4 {
+                    expected.push(view.at([i, j, k]));
+                }
+            }
+        }
+        assert_eq!(iter.collect::<Vec<i32>>(), expected);
+
+        // materializing the transposed view yields a contiguous view with the
+        // same output
+        let materialized = expected.clone().into_tensor_storage();
+        let materialized_view = materialized.as_shape([5, 3, 4]);
+        assert!(materialized_view.to_iter().is_contiguous);
+        assert_eq!(
+            materialized_view.to_iter().collect::<Vec<i32>>(),
+            transposed.to_iter().collect::<Vec<i32>>()
+        );
+    }
 }
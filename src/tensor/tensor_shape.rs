@@ -1,6 +1,6 @@
 use crate::tensor::{AxisIndex, Unitless};
 use num::ToPrimitive;
-use std::{collections::HashSet, iter::FromIterator};
+use std::{cmp::max, collections::HashSet, iter::FromIterator};
 
 /// The shape of an N-dimensional tensor has a size for each dimension, with an
 /// associated stride, e.g., a row-major 3 x 5 matrix will have a stride of 5
@@ -13,6 +13,37 @@ pub struct TensorShape {
 }
 
 impl TensorShape {
+    /// Creates a `TensorShape` from the `dims_strides` after checking that all
+    /// the dimensions are positive.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::tensor_shape::TensorShape;
+    ///
+    /// assert_eq!(
+    ///     TensorShape::try_new(vec![(3, 5), (5, 1)]),
+    ///     Ok(TensorShape::from([3, 5]))
+    /// );
+    /// assert!(TensorShape::try_new(vec![(3, 5), (0, 1)]).is_err());
+    /// ```
+    pub fn try_new(
+        dims_strides: Vec<(Unitless, Unitless)>,
+    ) -> Result<TensorShape, String> {
+        if let Some((axis, (dim, _))) = dims_strides
+            .iter()
+            .enumerate()
+            .find(|(_, (dim, _))| *dim <= 0)
+        {
+            return Err(format!(
+                "dimension at axis {} must be positive, got {}",
+                axis, dim
+            ));
+        }
+        Ok(TensorShape {
+            dims_strides,
+        })
+    }
+
     pub fn dims(&self) -> Vec<Unitless> {
         self.dims_strides.iter().map(|(dim, _)| *dim).collect()
     }
@@ -38,6 +69,35 @@ impl TensorShape {
         }
     }
 
+    /// Whether the strides are the same as those of the row-major layout
+    /// implied by the dims
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::tensor_shape::TensorShape;
+    ///
+    /// let shape = TensorShape::from([2, 3]);
+    /// assert!(shape.is_contiguous());
+    /// assert!(!shape.to_transposed(vec![1, 0]).is_contiguous());
+    /// ```
+    pub fn is_contiguous(&self) -> bool {
+        self.strides() == TensorShape::from(self.dims()).strides()
+    }
+
+    /// Returns the shape whose `i`-th axis is the `axes[i]`-th axis of `self`.
+    ///
+    /// # Panics
+    /// Panics if `axes` is not a permutation of `0..ndim`.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::tensor_shape::TensorShape;
+    ///
+    /// let shape = TensorShape::from([2, 3, 4]);
+    /// assert_eq!(shape.to_transposed(vec![2, 0, 1]), TensorShape {
+    ///     dims_strides: vec![(4, 1), (2, 12), (3, 4)]
+    /// });
+    /// ```
     pub fn to_transposed(&self, axes: Vec<AxisIndex>) -> TensorShape {
         assert_eq!(
             axes.len(),
@@ -46,6 +106,9 @@ impl TensorShape {
             axes.len(),
             self.dims_strides.len()
         );
+        if let Some(&axis) = axes.iter().find(|&&axis| axis >= self.ndim()) {
+            panic!("axis ({}) >= ndim ({})", axis, self.ndim());
+        }
         assert_eq!(
             HashSet::<AxisIndex>::from_iter(axes.clone().into_iter()).len(),
             self.dims_strides.len(),
@@ -57,6 +120,115 @@ impl TensorShape {
             dims_strides,
         }
     }
+
+    /// Returns the shape with all the dimensions of size 1 removed, together
+    /// with their strides.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::tensor_shape::TensorShape;
+    ///
+    /// let shape = TensorShape::from([1, 3, 1, 5]);
+    /// assert_eq!(shape.squeeze(), TensorShape::from([3, 5]));
+    /// ```
+    pub fn squeeze(&self) -> TensorShape {
+        TensorShape {
+            dims_strides: self
+                .dims_strides
+                .iter()
+                .filter(|(dim, _)| *dim != 1)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Returns the shape with a dimension of size 1 inserted at the `axis`,
+    /// where the `axis` can range from `0` to `self.ndim()` inclusive. The
+    /// stride of the new dimension is chosen to be consistent with the
+    /// row-major order, i.e. it is the extent of the dimension to its right.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::tensor_shape::TensorShape;
+    ///
+    /// let shape = TensorShape::from([3, 5]);
+    /// assert_eq!(shape.unsqueeze(0), TensorShape::from([1, 3, 5]));
+    /// assert_eq!(shape.unsqueeze(1), TensorShape::from([3, 1, 5]));
+    /// assert_eq!(shape.unsqueeze(2), TensorShape::from([3, 5, 1]));
+    /// ```
+    pub fn unsqueeze(&self, axis: AxisIndex) -> TensorShape {
+        assert!(
+            axis <= self.ndim(),
+            "axis ({}) > ndim ({})",
+            axis,
+            self.ndim()
+        );
+        let stride = match self.dims_strides.get(axis) {
+            Some(&(dim, stride)) => dim * stride,
+            None => 1,
+        };
+        let mut dims_strides = self.dims_strides.clone();
+        dims_strides.insert(axis, (1, stride));
+        TensorShape {
+            dims_strides,
+        }
+    }
+
+    /// Returns the shape resulting from broadcasting `self` and `other`
+    /// together following the numpy convention, i.e. the dimensions are
+    /// aligned from the right, and two aligned dimensions are compatible if
+    /// they are equal or if either of them is 1. A missing dimension is treated
+    /// as having a size of 1. The strides of the resulting shape are in the
+    /// row-major order.
+    ///
+    /// Returns an `Err` describing the first incompatible axis from the right,
+    /// where the axis index is with respect to the resulting shape.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::tensor_shape::TensorShape;
+    ///
+    /// let shape = TensorShape::from([3, 1]);
+    /// assert_eq!(
+    ///     shape.broadcast_shape(&TensorShape::from([2, 1, 4])),
+    ///     Ok(TensorShape::from([2, 3, 4]))
+    /// );
+    /// assert!(shape.broadcast_shape(&TensorShape::from([2, 4])).is_err());
+    /// ```
+    pub fn broadcast_shape(
+        &self,
+        other: &TensorShape,
+    ) -> Result<TensorShape, String> {
+        let lhs = self.dims();
+        let rhs = other.dims();
+        let ndim = max(lhs.len(), rhs.len());
+        let mut dims = vec![1; ndim];
+        for i in 0..ndim {
+            let l = if i < lhs.len() {
+                lhs[lhs.len() - 1 - i]
+            } else {
+                1
+            };
+            let r = if i < rhs.len() {
+                rhs[rhs.len() - 1 - i]
+            } else {
+                1
+            };
+            dims[ndim - 1 - i] = if l == r || r == 1 {
+                l
+            } else if l == 1 {
+                r
+            } else {
+                return Err(format!(
+                    "cannot broadcast axis {} of sizes {} and {}",
+                    ndim - 1 - i,
+                    l,
+                    r
+                ));
+            };
+        }
+        Ok(TensorShape::from(dims))
+    }
 }
 
 pub trait HasTensorShape {
@@ -192,8 +364,8 @@ mod tests {
         }
         {
             let empty_shape = TensorShape::from(Vec::<Unitless>::new());
-            assert_eq!(empty_shape.dims(), vec![]);
-            assert_eq!(empty_shape.strides(), vec![]);
+            assert_eq!(empty_shape.dims(), Vec::<Unitless>::new());
+            assert_eq!(empty_shape.strides(), Vec::<Unitless>::new());
             assert_eq!(empty_shape.ndim(), 0);
         }
     }
@@ -231,4 +403,139 @@ mod tests {
         check_from_iter!(&[3isize, 2, 5]);
         check_from_iter!(&[3usize, 2, 5]);
     }
+
+    #[test]
+    fn test_broadcast_shape() {
+        macro_rules! check {
+            ($a:expr, $b:expr, $expected:expr) => {
+                let a = TensorShape::from($a);
+                let b = TensorShape::from($b);
+                let expected = TensorShape::from($expected);
+                assert_eq!(a.broadcast_shape(&b), Ok(expected.clone()));
+                assert_eq!(b.broadcast_shape(&a), Ok(expected));
+            };
+        }
+        check!([3, 1], [1, 4], [3, 4]);
+        check!([3, 1], [4], [3, 4]);
+        check!([5, 3, 4], [3, 1], [5, 3, 4]);
+        check!([2, 1, 6], [7, 1], [2, 7, 6]);
+        check!([2, 3], [2, 3], [2, 3]);
+        check!(vec![2i64, 3], Vec::<i64>::new(), [2, 3]);
+
+        let broadcasted = TensorShape::from([3, 1])
+            .broadcast_shape(&TensorShape::from([1, 4]))
+            .unwrap();
+        assert_eq!(broadcasted.dims_strides, vec![(3, 4), (4, 1)]);
+
+        assert_eq!(
+            TensorShape::from([3, 2])
+                .broadcast_shape(&TensorShape::from([4, 2])),
+            Err("cannot broadcast axis 0 of sizes 3 and 4".to_string())
+        );
+        assert_eq!(
+            TensorShape::from([5, 3, 2])
+                .broadcast_shape(&TensorShape::from([3, 4])),
+            Err("cannot broadcast axis 2 of sizes 2 and 4".to_string())
+        );
+    }
+
+    #[test]
+    fn test_squeeze_and_unsqueeze() {
+        let shape = TensorShape::from([1, 3, 1, 5]);
+        let squeezed = shape.squeeze();
+        assert_eq!(squeezed.dims(), vec![3, 5]);
+        assert_eq!(squeezed.strides(), vec![5, 1]);
+        assert_eq!(squeezed.unsqueeze(0).unsqueeze(2), shape);
+        assert_eq!(squeezed.squeeze(), squeezed);
+
+        // the strides of a non-contiguous shape are preserved
+        let transposed =
+            TensorShape::from([1, 3, 5]).to_transposed(vec![2, 0, 1]);
+        assert_eq!(transposed.squeeze().dims_strides, vec![(5, 1), (3, 5)]);
+
+        let shape = TensorShape::from([2, 3, 4]);
+        for axis in 0..=shape.ndim() {
+            let unsqueezed = shape.unsqueeze(axis);
+            let mut dims = shape.dims();
+            dims.insert(axis, 1);
+            assert_eq!(unsqueezed, TensorShape::from(dims));
+            assert_eq!(unsqueezed.squeeze(), shape);
+            assert_eq!(unsqueezed.num_elements(), shape.num_elements());
+        }
+
+        assert_eq!(
+            TensorShape::from([1, 1]).squeeze(),
+            TensorShape::from(Vec::<Unitless>::new())
+        );
+        assert_eq!(
+            TensorShape::from(Vec::<Unitless>::new()).unsqueeze(0),
+            TensorShape::from([1])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unsqueeze_out_of_range() {
+        TensorShape::from([2, 3]).unsqueeze(3);
+    }
+
+    #[test]
+    fn test_to_transposed() {
+        let shape = TensorShape::from([2, 3, 4]);
+        assert_eq!(shape.to_transposed(vec![0, 1, 2]), shape);
+        assert_eq!(shape.to_transposed(vec![2, 1, 0]), TensorShape {
+            dims_strides: vec![(4, 1), (3, 4), (2, 12)]
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "axis (3) >= ndim (3)")]
+    fn test_to_transposed_axis_out_of_range() {
+        TensorShape::from([2, 3, 4]).to_transposed(vec![0, 1, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "axis (5) >= ndim (2)")]
+    fn test_to_transposed_repeated_axis_out_of_range() {
+        TensorShape::from([2, 3]).to_transposed(vec![5, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "all axes must be distinct")]
+    fn test_to_transposed_repeated_axis() {
+        TensorShape::from([2, 3]).to_transposed(vec![1, 1]);
+    }
+
+    #[test]
+    fn test_is_contiguous() {
+        let shape = TensorShape::from([2, 4, 3]);
+        assert!(shape.is_contiguous());
+        assert!(shape.to_transposed(vec![0, 1, 2]).is_contiguous());
+        assert!(!shape.to_transposed(vec![1, 0, 2]).is_contiguous());
+        assert!(!shape.to_transposed(vec![2, 1, 0]).is_contiguous());
+        assert!(TensorShape::from(Vec::<Unitless>::new()).is_contiguous());
+        assert!(TensorShape::try_new(vec![(2, 3), (3, 1)])
+            .unwrap()
+            .is_contiguous());
+        assert!(!TensorShape::try_new(vec![(2, 1), (3, 2)])
+            .unwrap()
+            .is_contiguous());
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            TensorShape::try_new(vec![(2, 12), (4, 3), (3, 1)]),
+            Ok(TensorShape::from([2, 4, 3]))
+        );
+        assert_eq!(
+            TensorShape::try_new(vec![]),
+            Ok(TensorShape::from(Vec::<Unitless>::new()))
+        );
+        assert_eq!(
+            TensorShape::try_new(vec![(2, 3), (0, 1)]),
+            Err("dimension at axis 1 must be positive, got 0".to_string())
+        );
+        assert!(TensorShape::try_new(vec![(-2, 1)]).is_err());
+    }
 }
@@ -1,8 +1,12 @@
 use crate::tensor::{
     ephemeral_view::{EphemeralView, ToEphemeralView},
     tensor_shape::TensorShape,
+    Unitless,
+};
+use std::{
+    ops::{Index, IndexMut},
+    slice, vec,
 };
-use std::ops::{Index, IndexMut};
 
 /// # An N-dimensional Tensor Storage
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -10,6 +14,56 @@ pub struct TensorStorage<Dtype> {
     pub vec: Vec<Dtype>,
 }
 
+impl<Dtype> TensorStorage<Dtype> {
+    /// Iterates over the elements in the order they are stored.
+    ///
+    /// # Example
+    /// ```
+    /// use math::tensor::tensor_storage::IntoTensorStorage;
+    ///
+    /// let mut storage = vec![1, 2, 3].into_tensor_storage();
+    /// storage.iter_mut().for_each(|x| *x *= 10);
+    /// assert_eq!(storage.iter().sum::<i32>(), 60);
+    /// assert_eq!(storage.into_iter().collect::<Vec<i32>>(), vec![10, 20, 30]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, Dtype> {
+        self.vec.iter()
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, Dtype> {
+        self.vec.iter_mut()
+    }
+}
+
+impl<Dtype> IntoIterator for TensorStorage<Dtype> {
+    type IntoIter = vec::IntoIter<Dtype>;
+    type Item = Dtype;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, Dtype> IntoIterator for &'a TensorStorage<Dtype> {
+    type IntoIter = slice::Iter<'a, Dtype>;
+    type Item = &'a Dtype;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.iter()
+    }
+}
+
+impl<'a, Dtype> IntoIterator for &'a mut TensorStorage<Dtype> {
+    type IntoIter = slice::IterMut<'a, Dtype>;
+    type Item = &'a mut Dtype;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.iter_mut()
+    }
+}
+
 impl<Dtype> Index<usize> for TensorStorage<Dtype>
 where
     Dtype: Copy,
@@ -32,6 +86,12 @@ where
 
 pub trait HasTensorData<Dtype> {
     fn data(&self) -> &TensorStorage<Dtype>;
+
+    /// The index into the `data` at which the first element of the tensor is
+    /// stored
+    fn offset(&self) -> Unitless {
+        0
+    }
 }
 
 pub trait IntoTensorStorage<Dtype> {
@@ -60,6 +120,7 @@ impl<'a, Dtype> ToEphemeralView<'a, Dtype> for TensorStorage<Dtype> {
         EphemeralView {
             shape: target_shape,
             data: &self,
+            offset: 0,
         }
     }
 }
@@ -128,4 +189,26 @@ mod tests {
             assert_eq!(shape.ndim(), 3);
         }
     }
+
+    #[test]
+    fn test_iter() {
+        let mut storage = vec![3, 1, 4, 1, 5].into_tensor_storage();
+        assert_eq!(storage.iter().copied().collect::<Vec<i32>>(), vec![
+            3, 1, 4, 1, 5
+        ]);
+        assert_eq!(storage.iter().max(), Some(&5));
+
+        for x in storage.iter_mut() {
+            *x *= 2;
+        }
+        assert_eq!(storage.vec, vec![6, 2, 8, 2, 10]);
+
+        for x in &mut storage {
+            *x += 1;
+        }
+        assert_eq!((&storage).into_iter().sum::<i32>(), 33);
+
+        let doubled: Vec<i32> = storage.into_iter().map(|x| x * 2).collect();
+        assert_eq!(doubled, vec![14, 6, 18, 6, 22]);
+    }
 }
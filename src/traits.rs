@@ -4,12 +4,28 @@ pub mod trait_impl;
 
 pub trait Collecting<E> {
     fn collect(&mut self, item: E);
+
+    /// Collects all the `items` one by one. Implementors can override it if a
+    /// more efficient bulk insertion is available.
+    fn collect_all<I: IntoIterator<Item = E>>(&mut self, items: I) {
+        for item in items {
+            self.collect(item);
+        }
+    }
 }
 
 pub trait HasDuplicate {
     fn has_duplicate(&self) -> bool;
 }
 
+/// Duplicate detection for sequences whose elements are already sorted.
+pub trait HasDuplicateSorted {
+    /// Same as `HasDuplicate::has_duplicate` but only scans adjacent elements
+    /// in a single pass, which requires the elements to be sorted. The result
+    /// is unspecified if they are not.
+    fn has_duplicate_sorted(&self) -> bool;
+}
+
 pub trait Slicing<I, O> {
     fn slice(self, input: I) -> O;
 }
@@ -20,4 +36,20 @@ pub trait SubsetIndexable<S, Output> {
 
 pub trait ToIterator<'s, I: Iterator<Item = R>, R> {
     fn to_iter(&'s self) -> I;
+
+    /// Returns the number of elements yielded by `to_iter` that satisfy the
+    /// predicate.
+    ///
+    /// # Example
+    /// ```
+    /// use math::{
+    ///     set::ordered_integer_set::OrderedIntegerSet, traits::ToIterator,
+    /// };
+    ///
+    /// let set = OrderedIntegerSet::from_slice(&[[1, 5], [8, 9]]);
+    /// assert_eq!(set.count_matching(|x| x % 2 == 0), 3);
+    /// ```
+    fn count_matching<P: Fn(&R) -> bool>(&'s self, pred: P) -> usize {
+        self.to_iter().filter(|r| pred(r)).count()
+    }
 }
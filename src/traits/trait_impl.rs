@@ -1,12 +1,17 @@
 use std::{ops::Deref, slice::Iter};
 
-use crate::traits::{Collecting, HasDuplicate, ToIterator};
+use crate::traits::{Collecting, HasDuplicate, HasDuplicateSorted, ToIterator};
 
 impl<T> Collecting<T> for Vec<T> {
     #[inline]
     fn collect(&mut self, item: T) {
         self.push(item);
     }
+
+    #[inline]
+    fn collect_all<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        self.extend(items);
+    }
 }
 
 impl<'a, T: Clone> Collecting<&'a T> for Vec<T>
@@ -39,9 +44,69 @@ impl<T: std::cmp::Ord> HasDuplicate for Vec<T> {
     }
 }
 
+impl<T: PartialEq> HasDuplicateSorted for [T] {
+    /// # Example
+    /// ```
+    /// use math::traits::HasDuplicateSorted;
+    ///
+    /// assert!(!vec![1, 2, 5, 7].has_duplicate_sorted());
+    /// assert!(vec![1, 2, 2, 7].has_duplicate_sorted());
+    /// ```
+    fn has_duplicate_sorted(&self) -> bool {
+        self.windows(2).any(|pair| pair[0] == pair[1])
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::traits::HasDuplicate;
+    use crate::traits::{
+        Collecting, HasDuplicate, HasDuplicateSorted, ToIterator,
+    };
+
+    #[test]
+    fn test_vec_count_matching() {
+        let v = vec![6, 2, 3, 9, 1, 10, 23];
+        assert_eq!(v.count_matching(|&&x| x % 2 == 0), 3);
+        assert_eq!(v.count_matching(|&&x| x > 5), 4);
+        assert_eq!(v.count_matching(|_| true), v.len());
+        assert_eq!(v.count_matching(|&&x| x > 100), 0);
+        assert_eq!(Vec::<i32>::new().count_matching(|_| true), 0);
+    }
+
+    #[test]
+    fn test_vec_collect_all() {
+        let mut v = vec![1, 2];
+        v.collect_all(vec![5, 3]);
+        v.collect_all(1..3);
+        v.collect_all(Vec::<i32>::new());
+        assert_eq!(v, vec![1, 2, 5, 3, 1, 2]);
+
+        let source = ["a".to_string(), "b".to_string()];
+        let mut v: Vec<String> = Vec::new();
+        v.collect_all(source.iter());
+        v.collect_all(source.iter().rev());
+        assert_eq!(v, vec!["a", "b", "b", "a"]);
+    }
+
+    #[test]
+    fn test_has_duplicate_sorted() {
+        fn test(v: Vec<i32>, expected: bool) {
+            assert_eq!(v.has_duplicate_sorted(), expected);
+            assert_eq!(v.has_duplicate(), expected);
+        }
+        test(vec![], false);
+        test(vec![3], false);
+        test(vec![1, 2, 3, 9, 10, 23], false);
+        test(vec![-5, -1, 0, 4], false);
+        test(vec![3, 3], true);
+        test(vec![1, 1, 2, 3], true);
+        test(vec![1, 2, 3, 9, 9, 23], true);
+        test(vec![1, 2, 3, 23, 23], true);
+        test(vec![4, 4, 4, 4], true);
+
+        assert!(!["ab", "abc", "cde", "hi"].has_duplicate_sorted());
+        assert!(["ab", "ab", "abc", "cde", "hi"].has_duplicate_sorted());
+    }
 
     #[test]
     fn test_has_duplciate() {